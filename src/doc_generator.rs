@@ -0,0 +1,969 @@
+struct Field {
+    name: std::rc::Rc<str>,
+    signature: String,
+    doc_comment: Option<std::rc::Rc<str>>,
+}
+
+struct Item {
+    name: std::rc::Rc<str>,
+    signature: String,
+    attributes: Vec<String>,
+    doc_comment: Option<std::rc::Rc<str>>,
+    fields: Vec<Field>,
+}
+
+struct Page {
+    name: String,
+    title: String,
+    items: Vec<Item>,
+    imports: Vec<(usize, Vec<std::rc::Rc<str>>)>,
+}
+
+pub struct DocGenerator {
+    pages: Vec<Page>,
+}
+
+impl Default for DocGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocGenerator {
+    pub fn new() -> Self {
+        DocGenerator { pages: Vec::new() }
+    }
+
+    pub fn generate(
+        mut self,
+        modules: &[super::module_resolver::Module],
+        output_directory: &std::path::Path,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let common_directory: std::path::PathBuf = Self::common_directory(modules);
+
+        let mut page_names: Vec<String> = vec!["index".to_string()];
+
+        for module in modules {
+            let relative_path: &std::path::Path = module
+                .path
+                .strip_prefix(&common_directory)
+                .unwrap_or(&module.path);
+
+            let name: String = Self::page_name(relative_path, &page_names);
+
+            page_names.push(name.clone());
+
+            self.pages.push(Page {
+                name,
+                title: relative_path.display().to_string(),
+                items: Self::collect_items(&module.program),
+                imports: Self::collect_imports(module, modules),
+            });
+        }
+
+        if let Err(error) = std::fs::create_dir_all(output_directory) {
+            error_handler.set_error(super::error_handler::Error::DocError(format!(
+                "cannot create directory: {}: {error}",
+                output_directory.display()
+            )));
+
+            return None;
+        }
+
+        Self::write_file(
+            &output_directory.join("index.html"),
+            &self.render_index_html(),
+            error_handler,
+        )?;
+        Self::write_file(
+            &output_directory.join("index.md"),
+            &self.render_index_markdown(),
+            error_handler,
+        )?;
+
+        for page in &self.pages {
+            Self::write_file(
+                &output_directory.join(format!("{}.html", page.name)),
+                &self.render_page_html(page),
+                error_handler,
+            )?;
+            Self::write_file(
+                &output_directory.join(format!("{}.md", page.name)),
+                &self.render_page_markdown(page),
+                error_handler,
+            )?;
+        }
+
+        Some(())
+    }
+
+    fn common_directory(modules: &[super::module_resolver::Module]) -> std::path::PathBuf {
+        let mut common_directory: Option<std::path::PathBuf> = None;
+
+        for module in modules {
+            let directory: &std::path::Path =
+                module.path.parent().unwrap_or(std::path::Path::new(""));
+
+            common_directory = Some(match common_directory {
+                Some(common_directory) => common_directory
+                    .components()
+                    .zip(directory.components())
+                    .take_while(|(common_component, component)| common_component == component)
+                    .map(|(common_component, _)| common_component)
+                    .collect(),
+                None => directory.to_path_buf(),
+            });
+        }
+
+        common_directory.unwrap_or_default()
+    }
+
+    fn page_name(relative_path: &std::path::Path, page_names: &[String]) -> String {
+        let name: String = relative_path
+            .with_extension("")
+            .components()
+            .filter_map(|component| match component {
+                std::path::Component::Normal(component) => {
+                    Some(component.to_string_lossy().replace('.', "_"))
+                }
+                std::path::Component::ParentDir => Some("_".to_string()),
+                std::path::Component::Prefix(..)
+                | std::path::Component::RootDir
+                | std::path::Component::CurDir => None,
+            })
+            .collect::<Vec<String>>()
+            .join(".");
+
+        let name: String = if name.is_empty() {
+            "module".to_string()
+        } else {
+            name
+        };
+
+        if !page_names.contains(&name) {
+            return name;
+        }
+
+        (2..)
+            .map(|index| format!("{name}-{index}"))
+            .find(|name| !page_names.contains(name))
+            .unwrap_or(name)
+    }
+
+    fn write_file(
+        path: &std::path::Path,
+        contents: &str,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        if let Err(error) = std::fs::write(path, contents) {
+            error_handler.set_error(super::error_handler::Error::DocError(format!(
+                "cannot write file: {}: {error}",
+                path.display()
+            )));
+
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn collect_imports(
+        module: &super::module_resolver::Module,
+        modules: &[super::module_resolver::Module],
+    ) -> Vec<(usize, Vec<std::rc::Rc<str>>)> {
+        let super::node::Node::ProgramStatement { body } = &module.program else {
+            return Vec::new();
+        };
+
+        body.iter()
+            .filter_map(|statement| match statement.as_ref() {
+                super::node::Node::ImportStatement { names, .. } => Some(names),
+                _ => None,
+            })
+            .zip(module.imports.iter())
+            .filter_map(|(names, import)| {
+                let index: usize = modules.iter().position(|module| &module.path == import)?;
+
+                let names: Vec<std::rc::Rc<str>> = names
+                    .iter()
+                    .filter_map(|name| match name.as_ref() {
+                        super::node::Node::IdentifierExpression { name } => Some(name.clone()),
+                        _ => None,
+                    })
+                    .collect();
+
+                Some((index, names))
+            })
+            .collect()
+    }
+
+    fn collect_items(program: &super::node::Node) -> Vec<Item> {
+        let mut items: Vec<Item> = Vec::new();
+
+        let super::node::Node::ProgramStatement { body } = program else {
+            return items;
+        };
+
+        for statement in body {
+            let super::node::Node::ExportStatement {
+                argument,
+                attributes: export_attributes,
+                ..
+            } = statement.as_ref()
+            else {
+                continue;
+            };
+
+            let super::node::Node::AssignmentStatement {
+                targets,
+                arguments,
+                attributes,
+                doc_comment,
+                ..
+            } = argument.as_ref()
+            else {
+                continue;
+            };
+
+            if targets.len() != arguments.len() {
+                continue;
+            }
+
+            for (target, argument) in targets.iter().zip(arguments.iter()) {
+                let super::node::Node::IdentifierExpression { name } = target.as_ref() else {
+                    continue;
+                };
+
+                let attributes: Vec<String> = export_attributes
+                    .iter()
+                    .chain(attributes.iter())
+                    .map(|attribute| Self::render_attribute(attribute))
+                    .collect();
+
+                match argument.as_ref() {
+                    super::node::Node::FuncExpression {
+                        doc_comment: func_doc_comment,
+                        ..
+                    } => items.push(Item {
+                        name: name.clone(),
+                        signature: Self::render_func_signature(name, argument),
+                        attributes,
+                        doc_comment: doc_comment.clone().or(func_doc_comment.clone()),
+                        fields: Vec::new(),
+                    }),
+                    super::node::Node::TableExpression { properties } => items.push(Item {
+                        name: name.clone(),
+                        signature: format!("{name} := table"),
+                        attributes,
+                        doc_comment: doc_comment.clone(),
+                        fields: Self::collect_fields(properties),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        items
+    }
+
+    fn collect_fields(properties: &[super::node::TableProperty]) -> Vec<Field> {
+        let mut fields: Vec<Field> = Vec::new();
+
+        for (key, value, key_type, doc_comment) in properties {
+            if key_type == &super::node::PropertyKeyType::Computed {
+                continue;
+            }
+
+            let name: std::rc::Rc<str> = match key.as_ref() {
+                super::node::Node::IdentifierExpression { name }
+                | super::node::Node::StringExpression { value: name }
+                | super::node::Node::LiteralExpression { value: name } => name.clone(),
+                _ => continue,
+            };
+
+            let signature: String = match value.as_ref() {
+                super::node::Node::FuncExpression { .. } => {
+                    Self::render_func_signature(&name, value)
+                }
+                _ => match Self::render_expression(value) {
+                    Some(value) => format!("{name}: {value}"),
+                    None => name.to_string(),
+                },
+            };
+
+            fields.push(Field {
+                name,
+                signature,
+                doc_comment: doc_comment.clone(),
+            });
+        }
+
+        fields
+    }
+
+    fn render_attribute(attribute: &super::node::Node) -> String {
+        let super::node::Node::Attribute { name, arguments } = attribute else {
+            return String::new();
+        };
+
+        if arguments.is_empty() {
+            return format!("@{name}");
+        }
+
+        let arguments: Vec<String> = arguments
+            .iter()
+            .map(|argument| Self::render_expression(argument).unwrap_or("...".to_string()))
+            .collect();
+
+        format!("@{name}({})", arguments.join(", "))
+    }
+
+    fn render_func_signature(name: &str, func: &super::node::Node) -> String {
+        let super::node::Node::FuncExpression {
+            parameters,
+            default_arguments,
+            variadic_parameter,
+            parameter_types,
+            variadic_parameter_type,
+            return_type,
+            ..
+        } = func
+        else {
+            return name.to_string();
+        };
+
+        let mut rendered_parameters: Vec<String> = Vec::new();
+
+        for (index, parameter) in parameters.iter().enumerate() {
+            let mut rendered_parameter: String =
+                Self::render_expression(parameter).unwrap_or_default();
+
+            if let Some(Some(parameter_type)) = parameter_types.get(index) {
+                rendered_parameter += &format!(": {}", Self::render_type(parameter_type));
+            }
+
+            if let Some(Some(default_argument)) = default_arguments.get(index) {
+                rendered_parameter += &format!(
+                    " = {}",
+                    Self::render_expression(default_argument).unwrap_or("...".to_string())
+                );
+            }
+
+            rendered_parameters.push(rendered_parameter);
+        }
+
+        if let Some(variadic_parameter) = variadic_parameter {
+            let mut rendered_parameter: String = format!(
+                "...{}",
+                Self::render_expression(variadic_parameter).unwrap_or_default()
+            );
+
+            if let Some(variadic_parameter_type) = variadic_parameter_type {
+                rendered_parameter += &format!(": {}", Self::render_type(variadic_parameter_type));
+            }
+
+            rendered_parameters.push(rendered_parameter);
+        }
+
+        let mut signature: String = format!("{name}({})", rendered_parameters.join(", "));
+
+        if let Some(return_type) = return_type {
+            signature += &format!(" -> {}", Self::render_type(return_type));
+        }
+
+        signature
+    }
+
+    fn render_type(annotation: &super::node::Node) -> String {
+        match annotation {
+            super::node::Node::PrimitiveType { name } => name.to_string(),
+            super::node::Node::TableType { properties } if properties.is_empty() => {
+                "table".to_string()
+            }
+            super::node::Node::TableType { properties } => {
+                let properties: Vec<String> = properties
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {}", Self::render_type(value)))
+                    .collect();
+
+                format!("table [{}]", properties.join(", "))
+            }
+            super::node::Node::FuncType {
+                parameter_types,
+                return_type,
+            } => {
+                let parameter_types: Vec<String> = parameter_types
+                    .iter()
+                    .map(|parameter_type| Self::render_type(parameter_type))
+                    .collect();
+
+                match return_type {
+                    Some(return_type) => format!(
+                        "func({}) -> {}",
+                        parameter_types.join(", "),
+                        Self::render_type(return_type)
+                    ),
+                    None => format!("func({})", parameter_types.join(", ")),
+                }
+            }
+            super::node::Node::UnionType { types } => {
+                let types: Vec<String> = types
+                    .iter()
+                    .map(|member| match member.as_ref() {
+                        super::node::Node::FuncType { .. } => {
+                            format!("({})", Self::render_type(member))
+                        }
+                        _ => Self::render_type(member),
+                    })
+                    .collect();
+
+                types.join(" | ")
+            }
+            super::node::Node::OptionalType { argument } => match argument.as_ref() {
+                super::node::Node::UnionType { .. } | super::node::Node::FuncType { .. } => {
+                    format!("({})?", Self::render_type(argument))
+                }
+                _ => format!("{}?", Self::render_type(argument)),
+            },
+            _ => "any".to_string(),
+        }
+    }
+
+    fn render_expression(expression: &super::node::Node) -> Option<String> {
+        match expression {
+            super::node::Node::LiteralExpression { value }
+            | super::node::Node::IdentifierExpression { name: value } => Some(value.to_string()),
+            super::node::Node::StringExpression { value } => Some(format!("{value:?}")),
+            super::node::Node::PrefixExpression {
+                argument,
+                operator: super::token::Token::SymbolMinus,
+            } => Some(format!("-{}", Self::render_expression(argument)?)),
+            _ => None,
+        }
+    }
+
+    fn find_link(&self, page: &Page, name: &str, extension: &str) -> Option<String> {
+        let (item_name, field_name): (&str, Option<&str>) = match name.split_once('.') {
+            Some((item_name, field_name)) => (item_name, Some(field_name)),
+            None => (name, None),
+        };
+
+        let pages = std::iter::once(page)
+            .chain(
+                page.imports
+                    .iter()
+                    .filter(|(_, names)| names.iter().any(|name| name.as_ref() == item_name))
+                    .map(|(index, _)| &self.pages[*index]),
+            )
+            .chain(page.imports.iter().map(|(index, _)| &self.pages[*index]))
+            .chain(self.pages.iter());
+
+        for page in pages {
+            for item in &page.items {
+                if item.name.as_ref() != item_name {
+                    continue;
+                }
+
+                match field_name {
+                    Some(field_name) => {
+                        if item
+                            .fields
+                            .iter()
+                            .any(|field| field.name.as_ref() == field_name)
+                        {
+                            return Some(format!(
+                                "{}.{extension}#{name}",
+                                Self::escape_link(&page.name)
+                            ));
+                        }
+                    }
+                    None => {
+                        return Some(format!(
+                            "{}.{extension}#{name}",
+                            Self::escape_link(&page.name)
+                        ));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn render_doc_comment(&self, page: &Page, doc_comment: &str, is_html: bool) -> String {
+        let mut rendered: String = String::new();
+        let mut rest: &str = doc_comment;
+
+        while let Some(start) = rest.find('[') {
+            let Some(length) = rest[start + 1..].find(']') else {
+                break;
+            };
+
+            let name: &str = &rest[start + 1..start + 1 + length];
+            let is_line_start: bool = rendered.is_empty() || rendered.ends_with('\n');
+
+            let link: Option<String> =
+                if rest[start + 1 + length + 1..].starts_with('(') || name.is_empty() {
+                    None
+                } else {
+                    self.find_link(page, name, if is_html { "html" } else { "md" })
+                };
+
+            match link {
+                Some(link) if is_html => {
+                    rendered += &Self::escape_html(&rest[..start]);
+                    rendered += &format!(
+                        "<a href=\"{}\"><code>{}</code></a>",
+                        Self::escape_html(&link),
+                        Self::escape_html(name)
+                    );
+                }
+                Some(link) => {
+                    rendered += &Self::escape_markdown(&rest[..start], is_line_start);
+                    rendered += &format!("[{}]({link})", Self::render_code_span(name));
+                }
+                None if is_html => {
+                    rendered += &Self::escape_html(&rest[..start + 1 + length + 1]);
+                }
+                None => {
+                    rendered +=
+                        &Self::escape_markdown(&rest[..start + 1 + length + 1], is_line_start);
+                }
+            }
+
+            rest = &rest[start + 1 + length + 1..];
+        }
+
+        if is_html {
+            rendered += &Self::escape_html(rest);
+
+            rendered
+                .split("\n\n")
+                .map(|paragraph| format!("<p>{paragraph}</p>"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            let is_line_start: bool = rendered.is_empty() || rendered.ends_with('\n');
+
+            rendered += &Self::escape_markdown(rest, is_line_start);
+
+            rendered
+        }
+    }
+
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    fn escape_markdown(text: &str, is_line_start: bool) -> String {
+        let mut escaped: String = String::new();
+
+        for (index, line) in text.split('\n').enumerate() {
+            if index > 0 {
+                escaped.push('\n');
+            }
+
+            let indentation: usize = if index > 0 || is_line_start {
+                line.len() - line.trim_start().len()
+            } else {
+                usize::MAX
+            };
+
+            for (position, c) in line.char_indices() {
+                let is_block_marker: bool = (position == indentation
+                    && matches!(c, '#' | '-' | '+' | '='))
+                    || (matches!(c, '.' | ')')
+                        && position > indentation
+                        && line[indentation..position]
+                            .chars()
+                            .all(|c| c.is_ascii_digit()));
+
+                if is_block_marker
+                    || matches!(
+                        c,
+                        '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '&'
+                    )
+                {
+                    escaped.push('\\');
+                }
+
+                escaped.push(c);
+            }
+        }
+
+        escaped
+    }
+
+    fn render_code_span(text: &str) -> String {
+        let longest_run: usize = text
+            .split(|c: char| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+
+        let fence: String = "`".repeat(longest_run + 1);
+
+        if text.starts_with('`') || text.ends_with('`') {
+            format!("{fence} {text} {fence}")
+        } else {
+            format!("{fence}{text}{fence}")
+        }
+    }
+
+    fn render_code_block(text: &str) -> String {
+        let longest_run: usize = text
+            .split(|c: char| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+
+        let fence: String = "`".repeat(std::cmp::max(longest_run + 1, 3));
+
+        format!("{fence}\n{text}\n{fence}\n")
+    }
+
+    fn escape_link(link: &str) -> String {
+        link.replace('%', "%25")
+            .replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29")
+            .replace('<', "%3C")
+            .replace('>', "%3E")
+    }
+
+    fn render_html_document(&self, title: &str, content: &str) -> String {
+        let navigation: Vec<String> = self
+            .pages
+            .iter()
+            .map(|page| {
+                format!(
+                    "<li><a href=\"{}.html\">{}</a></li>",
+                    Self::escape_html(&page.name),
+                    Self::escape_html(&page.title)
+                )
+            })
+            .collect();
+
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             </head>\n<body>\n<nav>\n<a href=\"index.html\">index</a>\n<ul>\n{}\n</ul>\n</nav>\n\
+             <main>\n{content}</main>\n</body>\n</html>\n",
+            Self::escape_html(title),
+            navigation.join("\n")
+        )
+    }
+
+    fn render_index_html(&self) -> String {
+        let mut content: String = String::from("<h1>index</h1>\n");
+
+        for page in &self.pages {
+            content += &format!(
+                "<h2><a href=\"{}.html\">{}</a></h2>\n<ul>\n",
+                Self::escape_html(&page.name),
+                Self::escape_html(&page.title)
+            );
+
+            for item in &page.items {
+                content += &format!(
+                    "<li><a href=\"{}.html#{}\"><code>{}</code></a></li>\n",
+                    Self::escape_html(&page.name),
+                    Self::escape_html(&item.name),
+                    Self::escape_html(&item.name)
+                );
+            }
+
+            content += "</ul>\n";
+        }
+
+        self.render_html_document("index", &content)
+    }
+
+    fn render_page_html(&self, page: &Page) -> String {
+        let mut content: String = format!("<h1>{}</h1>\n", Self::escape_html(&page.title));
+
+        for item in &page.items {
+            content += &format!(
+                "<section id=\"{}\">\n<h2><code>{}</code></h2>\n",
+                Self::escape_html(&item.name),
+                Self::escape_html(&item.name)
+            );
+
+            for attribute in &item.attributes {
+                content += &format!("<p><code>{}</code></p>\n", Self::escape_html(attribute));
+            }
+
+            content += &format!(
+                "<pre><code>{}</code></pre>\n",
+                Self::escape_html(&item.signature)
+            );
+
+            if let Some(doc_comment) = &item.doc_comment {
+                content += &self.render_doc_comment(page, doc_comment, true);
+                content += "\n";
+            }
+
+            for field in &item.fields {
+                content += &format!(
+                    "<h3 id=\"{}.{}\"><code>{}</code></h3>\n",
+                    Self::escape_html(&item.name),
+                    Self::escape_html(&field.name),
+                    Self::escape_html(&field.signature)
+                );
+
+                if let Some(doc_comment) = &field.doc_comment {
+                    content += &self.render_doc_comment(page, doc_comment, true);
+                    content += "\n";
+                }
+            }
+
+            content += "</section>\n";
+        }
+
+        self.render_html_document(&page.title, &content)
+    }
+
+    fn render_index_markdown(&self) -> String {
+        let mut content: String = String::from("# index\n");
+
+        for page in &self.pages {
+            content += &format!(
+                "\n## [{}]({}.md)\n\n",
+                Self::escape_markdown(&page.title, false),
+                Self::escape_link(&page.name)
+            );
+
+            for item in &page.items {
+                content += &format!(
+                    "- [{}]({}.md#{})\n",
+                    Self::render_code_span(&item.name),
+                    Self::escape_link(&page.name),
+                    item.name
+                );
+            }
+        }
+
+        content
+    }
+
+    fn render_page_markdown(&self, page: &Page) -> String {
+        let mut content: String = format!(
+            "[index](index.md)\n\n# {}\n",
+            Self::escape_markdown(&page.title, false)
+        );
+
+        for item in &page.items {
+            content += &format!(
+                "\n<a id=\"{}\"></a>\n## {}\n\n",
+                Self::escape_html(&item.name),
+                Self::render_code_span(&item.name)
+            );
+
+            for attribute in &item.attributes {
+                content += &format!("{}\n\n", Self::render_code_span(attribute));
+            }
+
+            content += &Self::render_code_block(&item.signature);
+
+            if let Some(doc_comment) = &item.doc_comment {
+                content += &format!("\n{}\n", self.render_doc_comment(page, doc_comment, false));
+            }
+
+            for field in &item.fields {
+                content += &format!(
+                    "\n<a id=\"{}.{}\"></a>\n### {}\n",
+                    Self::escape_html(&item.name),
+                    Self::escape_html(&field.name),
+                    Self::render_code_span(&field.signature)
+                );
+
+                if let Some(doc_comment) = &field.doc_comment {
+                    content +=
+                        &format!("\n{}\n", self.render_doc_comment(page, doc_comment, false));
+                }
+            }
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::ErrorHandler;
+
+    fn page(name: &str, items: &[(&str, &[&str])], imports: Vec<(usize, &[&str])>) -> super::Page {
+        super::Page {
+            name: name.to_string(),
+            title: name.to_string(),
+            items: items
+                .iter()
+                .map(|(name, fields)| super::Item {
+                    name: (*name).into(),
+                    signature: String::new(),
+                    attributes: Vec::new(),
+                    doc_comment: None,
+                    fields: fields
+                        .iter()
+                        .map(|name| super::Field {
+                            name: (*name).into(),
+                            signature: String::new(),
+                            doc_comment: None,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            imports: imports
+                .into_iter()
+                .map(|(index, names)| (index, names.iter().map(|name| (*name).into()).collect()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn page_names() {
+        let page_names: Vec<String> = vec!["index".to_string(), "lib.util".to_string()];
+
+        for (path, name) in [
+            ("main.luma", "main"),
+            ("lib/util.luma", "lib.util-2"),
+            ("lib/util.test.luma", "lib.util_test"),
+            ("../shared/a.luma", "_.shared.a"),
+            ("index.luma", "index-2"),
+        ] {
+            assert_eq!(
+                super::DocGenerator::page_name(std::path::Path::new(path), &page_names),
+                name,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn links_prefer_the_documented_module_and_its_imports() {
+        let generator: super::DocGenerator = super::DocGenerator {
+            pages: vec![
+                page("other", &[("f", &[]), ("g", &[]), ("h", &[])], Vec::new()),
+                page("a", &[("f", &[]), ("g", &[])], Vec::new()),
+                page("b", &[("g", &[]), ("t", &["name"])], Vec::new()),
+                page(
+                    "main",
+                    &[("f", &[])],
+                    vec![(1, &["f"] as &[&str]), (2, &["g"])],
+                ),
+            ],
+        };
+
+        let main: &super::Page = &generator.pages[3];
+
+        for (name, link) in [
+            ("f", Some("main.md#f")),
+            ("g", Some("b.md#g")),
+            ("t.name", Some("b.md#t.name")),
+            ("t.age", None),
+            ("h", Some("other.md#h")),
+            ("missing", None),
+        ] {
+            assert_eq!(
+                generator.find_link(main, name, "md").as_deref(),
+                link,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn markdown_escaping() {
+        assert_eq!(
+            super::DocGenerator::escape_markdown("a *b* [c] <d> `e` | f_g ~h & \\", false),
+            "a \\*b\\* \\[c\\] \\<d\\> \\`e\\` \\| f\\_g \\~h \\& \\\\"
+        );
+        assert_eq!(
+            super::DocGenerator::escape_markdown("# a\n  - b\n1. c\n12) d\na - 1.", true),
+            "\\# a\n  \\- b\n1\\. c\n12\\) d\na - 1."
+        );
+        assert_eq!(super::DocGenerator::escape_markdown("# a", false), "# a");
+    }
+
+    #[test]
+    fn code_spans_and_links() {
+        assert_eq!(super::DocGenerator::render_code_span("a"), "`a`");
+        assert_eq!(super::DocGenerator::render_code_span("a`b"), "``a`b``");
+        assert_eq!(super::DocGenerator::render_code_span("`a"), "`` `a ``");
+        assert_eq!(
+            super::DocGenerator::render_code_block("```"),
+            "````\n```\n````\n"
+        );
+        assert_eq!(
+            super::DocGenerator::escape_link("a b(1)<%>"),
+            "a%20b%281%29%3C%25%3E"
+        );
+    }
+
+    #[test]
+    fn doc_comment_links() {
+        let generator: super::DocGenerator = super::DocGenerator {
+            pages: vec![page("main", &[("f", &[])], Vec::new())],
+        };
+
+        let main: &super::Page = &generator.pages[0];
+
+        assert_eq!(
+            generator.render_doc_comment(main, "see [f] and [g], *not* [f](x)", false),
+            "see [`f`](main.md#f) and \\[g\\], \\*not\\* \\[f\\](x)"
+        );
+        assert_eq!(
+            generator.render_doc_comment(main, "see [f] & <g>\n\nmore", true),
+            "<p>see <a href=\"main.html#f\"><code>f</code></a> &amp; &lt;g&gt;</p>\n<p>more</p>"
+        );
+    }
+
+    #[test]
+    fn generates_pages() {
+        let directory: std::path::PathBuf =
+            std::env::temp_dir().join(format!("lumascript-doc-generator-{}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&directory);
+
+        std::fs::create_dir_all(directory.join("lib")).unwrap();
+        std::fs::write(
+            directory.join("main.luma"),
+            "import { add } from \"lib/util\"\n/// uses [add]\nexport f := func { }",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("lib/util.luma"),
+            "/// adds\n@inline\nexport add := func (a, b = 1) { a + b }",
+        )
+        .unwrap();
+
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let modules: Vec<super::super::module_resolver::Module> =
+            super::super::module_resolver::ModuleResolver::new()
+                .resolve(&directory.join("main.luma"), &mut error_handler)
+                .unwrap();
+
+        super::DocGenerator::new()
+            .generate(&modules, &directory.join("doc"), &mut error_handler)
+            .unwrap();
+
+        let read = |name: &str| std::fs::read_to_string(directory.join("doc").join(name));
+
+        let main: String = read("main.md").unwrap();
+        let util: String = read("lib.util.md").unwrap();
+
+        assert!(read("index.html").is_ok());
+        assert!(read("index.md").is_ok());
+        assert!(read("main.html").is_ok());
+        assert!(read("lib.util.html").is_ok());
+        assert!(main.contains("uses [`add`](lib.util.md#add)"), "{main}");
+        assert!(util.contains("`@inline`"), "{util}");
+        assert!(util.contains("add(a, b = 1)"), "{util}");
+        assert!(util.contains("adds"), "{util}");
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,20 +1,54 @@
+#[derive(Debug, PartialEq)]
 pub enum Error {
     SyntaxError(String),
+    ModuleError(String),
+    ResolveError(String),
+    TypeError(String),
+    DocError(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Warning {
+    LintWarning(String),
 }
 
 pub struct ErrorHandler {
     error: Option<Error>,
+    warnings: Vec<Warning>,
+}
+
+impl Default for ErrorHandler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ErrorHandler {
     pub fn new() -> Self {
-        ErrorHandler { error: None }
+        ErrorHandler {
+            error: None,
+            warnings: Vec::new(),
+        }
     }
 
     pub fn set_error(&mut self, error: Error) {
         self.error = Some(error);
     }
 
+    pub fn add_warning(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+
+    #[cfg(test)]
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    #[cfg(test)]
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
     pub fn print_error(&self) {
         if let Some(error) = &self.error {
             match error {
@@ -22,9 +56,36 @@ impl ErrorHandler {
                     println!("SyntaxError:");
                     println!("    {message}");
                 }
+                Error::ModuleError(message) => {
+                    println!("ModuleError:");
+                    println!("    {message}");
+                }
+                Error::ResolveError(message) => {
+                    println!("ResolveError:");
+                    println!("    {message}");
+                }
+                Error::TypeError(message) => {
+                    println!("TypeError:");
+                    println!("    {message}");
+                }
+                Error::DocError(message) => {
+                    println!("DocError:");
+                    println!("    {message}");
+                }
             }
         } else {
             println!("no error set");
         }
     }
+
+    pub fn print_warnings(&self) {
+        for warning in &self.warnings {
+            match warning {
+                Warning::LintWarning(message) => {
+                    println!("LintWarning:");
+                    println!("    {message}");
+                }
+            }
+        }
+    }
 }
@@ -2,6 +2,9 @@ pub struct Lexer<'a> {
     char_indices: std::str::CharIndices<'a>,
     current_character: Option<char>,
     next_character: Option<char>,
+    interpolation_depths: Vec<usize>,
+    line: usize,
+    token_line: usize,
 }
 
 impl<'a> Lexer<'a> {
@@ -10,15 +13,39 @@ impl<'a> Lexer<'a> {
             char_indices: input.char_indices(),
             current_character: None,
             next_character: None,
+            interpolation_depths: Vec::new(),
+            line: 1,
+            token_line: 1,
         };
 
         lexer.read_character();
         lexer.read_character();
 
+        if lexer.peek_current_character() == Some(&'\u{feff}') {
+            lexer.read_character();
+        }
+
+        if lexer.peek_current_character() == Some(&'#') && lexer.peek_next_character() == Some(&'!')
+        {
+            while let Some(&c) = lexer.peek_current_character() {
+                if c == '\n' || c == '\r' {
+                    break;
+                }
+
+                lexer.read_character();
+            }
+        }
+
         lexer
     }
 
     fn read_character(&mut self) {
+        if self.current_character == Some('\n')
+            || (self.current_character == Some('\r') && self.next_character != Some('\n'))
+        {
+            self.line += 1;
+        }
+
         self.current_character = std::mem::take(&mut self.next_character);
 
         if let Some((_, c)) = self.char_indices.next() {
@@ -35,9 +62,10 @@ impl<'a> Lexer<'a> {
     }
 
     fn skip_whitespace(&mut self) {
-        while let Some(c) = self.peek_current_character() {
+        while let Some(&c) = self.peek_current_character() {
             match c {
-                ' ' | '\t' => {
+                '\n' | '\r' => break,
+                _ if c.is_whitespace() => {
                     self.read_character();
                 }
                 _ => break,
@@ -45,13 +73,32 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn consume_int(&mut self) -> Option<super::token::Token> {
+    fn is_identifier_start(c: char) -> bool {
+        c == '_' || unicode_xid::UnicodeXID::is_xid_start(c)
+    }
+
+    fn is_identifier_continue(c: char) -> bool {
+        unicode_xid::UnicodeXID::is_xid_continue(c)
+    }
+
+    fn normalize_identifier(value: &str) -> std::rc::Rc<str> {
+        std::rc::Rc::from(
+            unicode_normalization::UnicodeNormalization::nfc(value).collect::<String>(),
+        )
+    }
+
+    fn consume_number(&mut self) -> Option<super::token::Token> {
         let mut value: String = String::new();
 
-        while let Some(c) = self.peek_current_character() {
+        while let Some(&c) = self.peek_current_character() {
             match c {
                 '0'..='9' => {
-                    value.push(*c);
+                    value.push(c);
+                    self.read_character();
+                }
+                '.' if value.contains('.') => break,
+                '.' if matches!(self.peek_next_character(), Some('0'..='9')) => {
+                    value.push('.');
                     self.read_character();
                 }
                 _ => break,
@@ -64,18 +111,19 @@ impl<'a> Lexer<'a> {
     fn consume_word(&mut self) -> Option<super::token::Token> {
         let mut value: String = String::new();
 
-        while let Some(c) = self.peek_current_character() {
-            match c {
-                'a'..='z' | 'A'..='Z' | '_' | '0'..='9' => {
-                    value.push(*c);
-                    self.read_character();
-                }
-                _ => break,
+        while let Some(&c) = self.peek_current_character() {
+            if !Self::is_identifier_continue(c) {
+                break;
             }
+
+            value.push(c);
+            self.read_character();
         }
 
-        Some(match value.as_str() {
-            "none" | "true" | "false" => super::token::Token::Literal(std::rc::Rc::from(value)),
+        let value: std::rc::Rc<str> = Self::normalize_identifier(&value);
+
+        Some(match value.as_ref() {
+            "none" | "true" | "false" => super::token::Token::Literal(value),
             "if" => super::token::Token::KeywordIf,
             "else" => super::token::Token::KeywordElse,
             "loop" => super::token::Token::KeywordLoop,
@@ -84,24 +132,174 @@ impl<'a> Lexer<'a> {
             "return" => super::token::Token::KeywordReturn,
             "break" => super::token::Token::KeywordBreak,
             "continue" => super::token::Token::KeywordContinue,
-            _ => super::token::Token::Identifier(std::rc::Rc::from(value)),
+            "match" => super::token::Token::KeywordMatch,
+            "import" => super::token::Token::KeywordImport,
+            "export" => super::token::Token::KeywordExport,
+            "const" => super::token::Token::KeywordConst,
+            "throw" => super::token::Token::KeywordThrow,
+            "try" => super::token::Token::KeywordTry,
+            "catch" => super::token::Token::KeywordCatch,
+            "finally" => super::token::Token::KeywordFinally,
+            "defer" => super::token::Token::KeywordDefer,
+            "yield" => super::token::Token::KeywordYield,
+            _ => super::token::Token::Identifier(value),
         })
     }
 
+    fn consume_label(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::token::Token> {
+        self.read_character();
+
+        if !self
+            .peek_current_character()
+            .is_some_and(|&c| Self::is_identifier_start(c))
+        {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(
+                "unexpected character: '".to_string(),
+            ));
+
+            return None;
+        }
+
+        let mut value: String = String::new();
+
+        while let Some(&c) = self.peek_current_character() {
+            if !Self::is_identifier_continue(c) {
+                break;
+            }
+
+            value.push(c);
+            self.read_character();
+        }
+
+        Some(super::token::Token::Label(Self::normalize_identifier(
+            &value,
+        )))
+    }
+
+    fn consume_doc_comment(&mut self) -> Option<super::token::Token> {
+        self.read_character();
+        self.read_character();
+
+        if self.peek_current_character() == Some(&' ') {
+            self.read_character();
+        }
+
+        let mut value: String = String::new();
+
+        while let Some(&c) = self.peek_current_character() {
+            if c == '\n' || c == '\r' {
+                break;
+            }
+
+            value.push(c);
+            self.read_character();
+        }
+
+        Some(super::token::Token::DocComment(std::rc::Rc::from(value)))
+    }
+
+    fn consume_string(
+        &mut self,
+        is_continuation: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::token::Token> {
+        self.read_character();
+
+        let mut value: String = String::new();
+
+        loop {
+            match self.peek_current_character() {
+                Some('"') => {
+                    self.read_character();
+
+                    return Some(if is_continuation {
+                        super::token::Token::StringTail(std::rc::Rc::from(value))
+                    } else {
+                        super::token::Token::String(std::rc::Rc::from(value))
+                    });
+                }
+                Some('{') => {
+                    self.read_character();
+                    self.interpolation_depths.push(0);
+
+                    return Some(if is_continuation {
+                        super::token::Token::StringMiddle(std::rc::Rc::from(value))
+                    } else {
+                        super::token::Token::StringHead(std::rc::Rc::from(value))
+                    });
+                }
+                Some('\\') => {
+                    self.read_character();
+
+                    let c: char = match self.peek_current_character() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('0') => '\0',
+                        Some(c @ ('\\' | '"' | '{' | '}')) => *c,
+                        Some(c) => {
+                            error_handler.set_error(super::error_handler::Error::SyntaxError(
+                                format!("unknown escape sequence: \\{c}"),
+                            ));
+
+                            return None;
+                        }
+                        None => break,
+                    };
+
+                    value.push(c);
+                    self.read_character();
+                }
+                Some('\r') => {
+                    value.push('\n');
+                    self.read_character();
+
+                    if self.peek_current_character() == Some(&'\n') {
+                        self.read_character();
+                    }
+                }
+                Some(c) => {
+                    value.push(*c);
+                    self.read_character();
+                }
+                None => break,
+            }
+        }
+
+        error_handler.set_error(super::error_handler::Error::SyntaxError(
+            "unterminated string literal".to_string(),
+        ));
+
+        None
+    }
+
+    pub fn token_line(&self) -> usize {
+        self.token_line
+    }
+
     pub fn next(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::token::Token> {
         self.skip_whitespace();
 
+        self.token_line = self.line;
+
         if let Some(c) = self.peek_current_character() {
             match c {
-                '0'..='9' => self.consume_int(),
-                'a'..='z' | 'A'..='Z' | '_' => self.consume_word(),
+                '0'..='9' => self.consume_number(),
+                c if Self::is_identifier_start(*c) => self.consume_word(),
+                '\'' => self.consume_label(error_handler),
                 '=' => {
                     let token: super::token::Token = if self.peek_next_character() == Some(&'=') {
                         self.read_character();
                         super::token::Token::SymbolEqualEqual
+                    } else if self.peek_next_character() == Some(&'>') {
+                        self.read_character();
+                        super::token::Token::SymbolEqualGreater
                     } else {
                         super::token::Token::SymbolEqual
                     };
@@ -118,7 +316,12 @@ impl<'a> Lexer<'a> {
                     Some(token)
                 }
                 '-' => {
-                    let token: super::token::Token = super::token::Token::SymbolMinus;
+                    let token: super::token::Token = if self.peek_next_character() == Some(&'>') {
+                        self.read_character();
+                        super::token::Token::SymbolMinusGreater
+                    } else {
+                        super::token::Token::SymbolMinus
+                    };
 
                     self.read_character();
 
@@ -136,6 +339,12 @@ impl<'a> Lexer<'a> {
 
                     self.read_character();
 
+                    if self.peek_current_character() == Some(&'/')
+                        && self.peek_next_character() == Some(&'/')
+                    {
+                        return self.consume_doc_comment();
+                    }
+
                     Some(token)
                 }
                 '!' => {
@@ -196,6 +405,9 @@ impl<'a> Lexer<'a> {
                     let token: super::token::Token = if self.peek_next_character() == Some(&'|') {
                         self.read_character();
                         super::token::Token::SymbolPipePipe
+                    } else if self.peek_next_character() == Some(&'>') {
+                        self.read_character();
+                        super::token::Token::SymbolPipeGreater
                     } else {
                         super::token::Token::SymbolPipe
                     };
@@ -225,14 +437,29 @@ impl<'a> Lexer<'a> {
 
                     Some(token)
                 }
+                '"' => self.consume_string(false, error_handler),
                 '{' => {
                     let token: super::token::Token = super::token::Token::SymbolLeftBrace;
 
+                    if let Some(depth) = self.interpolation_depths.last_mut() {
+                        *depth += 1;
+                    }
+
                     self.read_character();
 
                     Some(token)
                 }
                 '}' => {
+                    match self.interpolation_depths.last_mut() {
+                        Some(0) => {
+                            self.interpolation_depths.pop();
+
+                            return self.consume_string(true, error_handler);
+                        }
+                        Some(depth) => *depth -= 1,
+                        None => {}
+                    }
+
                     let token: super::token::Token = super::token::Token::SymbolRightBrace;
 
                     self.read_character();
@@ -261,7 +488,46 @@ impl<'a> Lexer<'a> {
                     Some(token)
                 }
                 '.' => {
-                    let token: super::token::Token = super::token::Token::SymbolDot;
+                    let token: super::token::Token = if self.peek_next_character() == Some(&'.') {
+                        self.read_character();
+
+                        if self.peek_next_character() == Some(&'.') {
+                            self.read_character();
+                            super::token::Token::SymbolDotDotDot
+                        } else if self.peek_next_character() == Some(&'=') {
+                            self.read_character();
+                            super::token::Token::SymbolDotDotEqual
+                        } else {
+                            super::token::Token::SymbolDotDot
+                        }
+                    } else {
+                        super::token::Token::SymbolDot
+                    };
+
+                    self.read_character();
+
+                    Some(token)
+                }
+                '?' => {
+                    let token: super::token::Token = if self.peek_next_character() == Some(&'.') {
+                        self.read_character();
+                        super::token::Token::SymbolQuestionDot
+                    } else if self.peek_next_character() == Some(&'[') {
+                        self.read_character();
+                        super::token::Token::SymbolQuestionLeftSquareBracket
+                    } else if self.peek_next_character() == Some(&'?') {
+                        self.read_character();
+                        super::token::Token::SymbolQuestionQuestion
+                    } else {
+                        super::token::Token::SymbolQuestion
+                    };
+
+                    self.read_character();
+
+                    Some(token)
+                }
+                '@' => {
+                    let token: super::token::Token = super::token::Token::SymbolAt;
 
                     self.read_character();
 
@@ -293,6 +559,17 @@ impl<'a> Lexer<'a> {
 
                     Some(token)
                 }
+                '\r' => {
+                    let token: super::token::Token = super::token::Token::NewLine;
+
+                    if self.peek_next_character() == Some(&'\n') {
+                        self.read_character();
+                    }
+
+                    self.read_character();
+
+                    Some(token)
+                }
                 _ => {
                     error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
                         "unexpected character: {c}"
@@ -306,3 +583,200 @@ impl<'a> Lexer<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::{Error, ErrorHandler};
+    use super::super::token::Token;
+
+    fn lex(input: &str) -> (Vec<Token>, ErrorHandler) {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let mut lexer: super::Lexer<'_> = super::Lexer::new(input);
+        let mut tokens: Vec<Token> = Vec::new();
+
+        while let Some(token) = lexer.next(&mut error_handler) {
+            if token == Token::Eof {
+                break;
+            }
+
+            tokens.push(token);
+        }
+
+        (tokens, error_handler)
+    }
+
+    #[test]
+    fn labels() {
+        let (tokens, error_handler) = lex("'outer: loop { break 'outer }");
+
+        assert_eq!(error_handler.error(), None);
+        assert_eq!(tokens[0], Token::Label("outer".into()));
+        assert_eq!(tokens[5], Token::Label("outer".into()));
+        assert_eq!(lex("'_a").0, vec![Token::Label("_a".into())]);
+    }
+
+    #[test]
+    fn label_must_start_like_an_identifier() {
+        for input in ["'1abc", "' a", "'"] {
+            let (_, error_handler) = lex(input);
+
+            assert_eq!(
+                error_handler.error(),
+                Some(&Error::SyntaxError("unexpected character: '".to_string())),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn optional_chaining_and_coalescing_operators() {
+        assert_eq!(
+            lex("a?.b?[c]?.(d) ?? e").0,
+            vec![
+                Token::Identifier("a".into()),
+                Token::SymbolQuestionDot,
+                Token::Identifier("b".into()),
+                Token::SymbolQuestionLeftSquareBracket,
+                Token::Identifier("c".into()),
+                Token::SymbolRightSquareBracket,
+                Token::SymbolQuestionDot,
+                Token::SymbolLeftParenthesis,
+                Token::Identifier("d".into()),
+                Token::SymbolRightParenthesis,
+                Token::SymbolQuestionQuestion,
+                Token::Identifier("e".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn interpolated_string_tokens() {
+        assert_eq!(
+            lex("\"a {b} c {d + 1}!\"").0,
+            vec![
+                Token::StringHead("a ".into()),
+                Token::Identifier("b".into()),
+                Token::StringMiddle(" c ".into()),
+                Token::Identifier("d".into()),
+                Token::SymbolPlus,
+                Token::Literal("1".into()),
+                Token::StringTail("!".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn string_escapes() {
+        assert_eq!(
+            lex("\"\\{x\\} \\n\\t\\\"\\\\\"").0,
+            vec![Token::String("{x} \n\t\"\\".into())]
+        );
+    }
+
+    #[test]
+    fn invalid_strings() {
+        for (input, message) in [
+            ("\"a \\q\"", "unknown escape sequence: \\q"),
+            ("\"a", "unterminated string literal"),
+        ] {
+            assert_eq!(
+                lex(input).1.error(),
+                Some(&Error::SyntaxError(message.to_string())),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn doc_comments() {
+        assert_eq!(
+            lex("///  padded\n///tight\nx").0,
+            vec![
+                Token::DocComment(" padded".into()),
+                Token::NewLine,
+                Token::DocComment("tight".into()),
+                Token::NewLine,
+                Token::Identifier("x".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unicode_identifiers_are_normalized() {
+        assert_eq!(
+            lex("café cafe\u{301} _x ñ1 'café").0,
+            vec![
+                Token::Identifier("café".into()),
+                Token::Identifier("café".into()),
+                Token::Identifier("_x".into()),
+                Token::Identifier("ñ1".into()),
+                Token::Label("café".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unicode_whitespace_separates_tokens() {
+        assert_eq!(
+            lex("a\u{a0}b\u{3000}c").0,
+            vec![
+                Token::Identifier("a".into()),
+                Token::Identifier("b".into()),
+                Token::Identifier("c".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(
+            lex("a\r\nb\rc\n\"d\r\ne\"").0,
+            vec![
+                Token::Identifier("a".into()),
+                Token::NewLine,
+                Token::Identifier("b".into()),
+                Token::NewLine,
+                Token::Identifier("c".into()),
+                Token::NewLine,
+                Token::String("d\ne".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn token_lines() {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let mut lexer: super::Lexer<'_> = super::Lexer::new("a\r\nb\rc\n\"d\ne\"\nf");
+        let mut lines: Vec<(Token, usize)> = Vec::new();
+
+        while let Some(token) = lexer.next(&mut error_handler) {
+            if token == Token::Eof {
+                break;
+            }
+
+            if token != Token::NewLine {
+                lines.push((token, lexer.token_line()));
+            }
+        }
+
+        assert_eq!(
+            lines,
+            vec![
+                (Token::Identifier("a".into()), 1),
+                (Token::Identifier("b".into()), 2),
+                (Token::Identifier("c".into()), 3),
+                (Token::String("d\ne".into()), 4),
+                (Token::Identifier("f".into()), 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn byte_order_mark_and_shebang() {
+        assert_eq!(
+            lex("\u{feff}#!/usr/bin/env luma\nx").0,
+            vec![Token::NewLine, Token::Identifier("x".into())]
+        );
+        assert_eq!(lex("\u{feff}x").0, vec![Token::Identifier("x".into())]);
+    }
+}
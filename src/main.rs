@@ -1,8 +1,12 @@
+pub mod doc_generator;
 pub mod error_handler;
 pub mod lexer;
+pub mod module_resolver;
 pub mod node;
 pub mod parser;
+pub mod resolver;
 pub mod token;
+pub mod typeck;
 
 fn run(input: &str, error_handler: &mut error_handler::ErrorHandler) -> Option<()> {
     let mut lexer: lexer::Lexer<'_> = lexer::Lexer::new(input);
@@ -10,14 +14,86 @@ fn run(input: &str, error_handler: &mut error_handler::ErrorHandler) -> Option<(
 
     let program_node: node::Node = parser.parse(error_handler)?;
 
+    resolver::Resolver::new().resolve(&program_node, error_handler)?;
+    typeck::TypeChecker::new().check(&program_node, error_handler)?;
+
     println!("{program_node:#?}");
 
     Some(())
 }
 
+fn run_module(
+    path: &std::path::Path,
+    error_handler: &mut error_handler::ErrorHandler,
+) -> Option<()> {
+    let modules: Vec<module_resolver::Module> =
+        module_resolver::ModuleResolver::new().resolve(path, error_handler)?;
+
+    for module in &modules {
+        resolver::Resolver::new().resolve(&module.program, error_handler)?;
+        typeck::TypeChecker::new().check(&module.program, error_handler)?;
+    }
+
+    for module in modules {
+        println!("{}:", module.path.display());
+        println!("{:#?}", module.program);
+    }
+
+    Some(())
+}
+
+fn run_doc(
+    entry_path: &std::path::Path,
+    output_directory: &std::path::Path,
+    error_handler: &mut error_handler::ErrorHandler,
+) -> Option<()> {
+    let modules: Vec<module_resolver::Module> =
+        module_resolver::ModuleResolver::new().resolve(entry_path, error_handler)?;
+
+    doc_generator::DocGenerator::new().generate(&modules, output_directory, error_handler)?;
+
+    println!("wrote documentation to {}", output_directory.display());
+
+    Some(())
+}
+
 pub fn main() {
     let mut error_handler: error_handler::ErrorHandler = error_handler::ErrorHandler::new();
 
+    if std::env::args().nth(1).as_deref() == Some("doc") {
+        let Some(path) = std::env::args().nth(2) else {
+            println!("usage: rust-lumascript-frontend doc <entry> [output directory]");
+
+            return;
+        };
+
+        let output_directory: String = std::env::args().nth(3).unwrap_or("doc".to_string());
+
+        if run_doc(
+            std::path::Path::new(&path),
+            std::path::Path::new(&output_directory),
+            &mut error_handler,
+        )
+        .is_none()
+        {
+            error_handler.print_error();
+        }
+
+        error_handler.print_warnings();
+
+        return;
+    }
+
+    if let Some(path) = std::env::args().nth(1) {
+        if run_module(std::path::Path::new(&path), &mut error_handler).is_none() {
+            error_handler.print_error();
+        }
+
+        error_handler.print_warnings();
+
+        return;
+    }
+
     let input: &str = "
         fib := func n {
             if n < 2 {
@@ -33,4 +109,6 @@ pub fn main() {
     if run(input, &mut error_handler).is_none() {
         error_handler.print_error();
     }
+
+    error_handler.print_warnings();
 }
@@ -0,0 +1,321 @@
+pub struct Module {
+    pub path: std::path::PathBuf,
+    pub program: super::node::Node,
+    pub imports: Vec<std::path::PathBuf>,
+}
+
+pub struct ModuleResolver {
+    modules: Vec<Module>,
+    import_stack: Vec<std::path::PathBuf>,
+}
+
+impl Default for ModuleResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModuleResolver {
+    pub fn new() -> Self {
+        ModuleResolver {
+            modules: Vec::new(),
+            import_stack: Vec::new(),
+        }
+    }
+
+    pub fn resolve(
+        mut self,
+        entry_path: &std::path::Path,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Vec<Module>> {
+        self.load_module(entry_path, error_handler)?;
+
+        Some(self.modules)
+    }
+
+    fn load_module(
+        &mut self,
+        path: &std::path::Path,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let path: std::path::PathBuf = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+        if let Some(index) = self.import_stack.iter().position(|import| import == &path) {
+            let cycle: Vec<String> = self.import_stack[index..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|import| import.display().to_string())
+                .collect();
+
+            error_handler.set_error(super::error_handler::Error::ModuleError(format!(
+                "import cycle: {}",
+                cycle.join(" -> ")
+            )));
+
+            return None;
+        }
+
+        if self.modules.iter().any(|module| module.path == path) {
+            return Some(());
+        }
+
+        let input: String = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                error_handler.set_error(super::error_handler::Error::ModuleError(format!(
+                    "cannot read module: {}: {error}",
+                    path.display()
+                )));
+
+                return None;
+            }
+        };
+
+        let mut lexer: super::lexer::Lexer<'_> = super::lexer::Lexer::new(&input);
+        let mut parser: super::parser::Parser<'_> = super::parser::Parser::new(&mut lexer);
+
+        let program: super::node::Node = parser.parse(error_handler)?;
+
+        let directory: std::path::PathBuf = path
+            .parent()
+            .map(std::path::Path::to_path_buf)
+            .unwrap_or_default();
+
+        self.import_stack.push(path.clone());
+
+        let mut imports: Vec<std::path::PathBuf> = Vec::new();
+
+        if let super::node::Node::ProgramStatement { body } = &program {
+            for statement in body {
+                if let super::node::Node::ImportStatement { source, names, .. } = statement.as_ref()
+                {
+                    let import_path: std::path::PathBuf = Self::resolve_path(&directory, source);
+
+                    self.load_module(&import_path, error_handler)?;
+                    self.check_imported_names(&import_path, source, names, error_handler)?;
+
+                    imports.push(
+                        import_path
+                            .canonicalize()
+                            .unwrap_or_else(|_| import_path.to_path_buf()),
+                    );
+                }
+            }
+        }
+
+        self.import_stack.pop();
+
+        self.modules.push(Module {
+            path,
+            program,
+            imports,
+        });
+
+        Some(())
+    }
+
+    fn check_imported_names(
+        &self,
+        path: &std::path::Path,
+        source: &str,
+        names: &[Box<super::node::Node>],
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let path: std::path::PathBuf = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+        let module: &Module = self.modules.iter().find(|module| module.path == path)?;
+
+        let mut exported_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+        if let super::node::Node::ProgramStatement { body } = &module.program {
+            for statement in body {
+                if let super::node::Node::ExportStatement { argument, .. } = statement.as_ref()
+                    && let super::node::Node::AssignmentStatement { targets, .. } =
+                        argument.as_ref()
+                {
+                    for target in targets {
+                        Self::collect_bound_names(target, &mut exported_names);
+                    }
+                }
+            }
+        }
+
+        for name in names {
+            if let super::node::Node::IdentifierExpression { name } = name.as_ref()
+                && !exported_names.contains(name)
+            {
+                error_handler.set_error(super::error_handler::Error::ModuleError(format!(
+                    "module \"{source}\" does not export: {name}"
+                )));
+
+                return None;
+            }
+        }
+
+        Some(())
+    }
+
+    fn collect_bound_names(target: &super::node::Node, names: &mut Vec<std::rc::Rc<str>>) {
+        match target {
+            super::node::Node::IdentifierExpression { name }
+            | super::node::Node::IdentifierPattern { name } => names.push(name.clone()),
+            super::node::Node::ArrayPattern { elements } => {
+                for element in elements {
+                    Self::collect_bound_names(element, names);
+                }
+            }
+            super::node::Node::TablePattern { properties } => {
+                for (_, value) in properties {
+                    Self::collect_bound_names(value, names);
+                }
+            }
+            super::node::Node::RestPattern { argument } => {
+                Self::collect_bound_names(argument, names);
+            }
+            _ => {}
+        }
+    }
+
+    fn resolve_path(directory: &std::path::Path, source: &str) -> std::path::PathBuf {
+        let mut path: std::path::PathBuf = directory.join(source);
+
+        if path.extension().is_none() {
+            path.set_extension("luma");
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::{Error, ErrorHandler};
+
+    fn write_modules(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let directory: std::path::PathBuf = std::env::temp_dir().join(format!(
+            "lumascript-module-resolver-{name}-{}",
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_dir_all(&directory);
+
+        for (path, input) in files {
+            let path: std::path::PathBuf = directory.join(path);
+
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, input).unwrap();
+        }
+
+        directory.canonicalize().unwrap()
+    }
+
+    fn resolve(directory: &std::path::Path) -> (Option<Vec<super::Module>>, ErrorHandler) {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let modules: Option<Vec<super::Module>> =
+            super::ModuleResolver::new().resolve(&directory.join("main.luma"), &mut error_handler);
+
+        std::fs::remove_dir_all(directory).unwrap();
+
+        (modules, error_handler)
+    }
+
+    #[test]
+    fn loads_imports_before_importers() {
+        let directory: std::path::PathBuf = write_modules(
+            "order",
+            &[
+                (
+                    "main.luma",
+                    "import \"lib/util\" as util\nimport { b } from \"b\"",
+                ),
+                ("lib/util.luma", "import { b } from \"../b\"\nexport a := b"),
+                ("b.luma", "export b := 1"),
+            ],
+        );
+
+        let (modules, error_handler) = resolve(&directory);
+
+        assert_eq!(error_handler.error(), None);
+
+        let modules: Vec<super::Module> = modules.unwrap();
+        let paths: Vec<&std::path::Path> =
+            modules.iter().map(|module| module.path.as_path()).collect();
+
+        assert_eq!(
+            paths,
+            [
+                directory.join("b.luma"),
+                directory.join("lib/util.luma"),
+                directory.join("main.luma"),
+            ]
+        );
+        assert_eq!(
+            modules[2].imports,
+            [directory.join("lib/util.luma"), directory.join("b.luma")]
+        );
+    }
+
+    #[test]
+    fn import_cycles() {
+        let directory: std::path::PathBuf = write_modules(
+            "cycle",
+            &[
+                ("main.luma", "import \"a\" as a"),
+                ("a.luma", "import \"b\" as b"),
+                ("b.luma", "import \"a\" as a"),
+            ],
+        );
+
+        let (modules, error_handler) = resolve(&directory);
+
+        assert!(modules.is_none());
+        assert_eq!(
+            error_handler.error(),
+            Some(&Error::ModuleError(format!(
+                "import cycle: {} -> {} -> {}",
+                directory.join("a.luma").display(),
+                directory.join("b.luma").display(),
+                directory.join("a.luma").display()
+            )))
+        );
+    }
+
+    #[test]
+    fn missing_exports() {
+        let directory: std::path::PathBuf = write_modules(
+            "exports",
+            &[
+                ("main.luma", "import { a, x, y } from \"lib\""),
+                ("lib.luma", "export a := 1\nexport [x] := [2]\ny := 3"),
+            ],
+        );
+
+        let (modules, error_handler) = resolve(&directory);
+
+        assert!(modules.is_none());
+        assert_eq!(
+            error_handler.error(),
+            Some(&Error::ModuleError(
+                "module \"lib\" does not export: y".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn missing_modules() {
+        let directory: std::path::PathBuf =
+            write_modules("missing", &[("main.luma", "import \"lib\" as lib")]);
+
+        let (modules, error_handler) = resolve(&directory);
+
+        assert!(modules.is_none());
+        assert!(matches!(
+            error_handler.error(),
+            Some(Error::ModuleError(message))
+                if message.starts_with(&format!(
+                    "cannot read module: {}: ",
+                    directory.join("lib.luma").display()
+                ))
+        ));
+    }
+}
@@ -5,29 +5,100 @@ pub enum Node {
     },
     BlockStatement {
         body: Vec<Box<Node>>,
+        value: Option<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
     },
     IfStatement {
         argument: Box<Node>,
         consequent_body: Box<Node>,
         alternate_body: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
     },
     LoopStatement {
+        label: Option<std::rc::Rc<str>>,
         body: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
     },
     ReturnStatement {
-        argument: Box<Node>,
+        arguments: Vec<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    BreakStatement {
+        label: Option<std::rc::Rc<str>>,
+        argument: Option<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    ContinueStatement {
+        label: Option<std::rc::Rc<str>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
     },
-    BreakStatement,
-    ContinueStatement,
     AssignmentStatement {
-        target: Box<Node>,
-        argument: Box<Node>,
+        targets: Vec<Box<Node>>,
+        arguments: Vec<Box<Node>>,
+        type_annotations: Vec<Option<Box<Node>>>,
         is_decleration: bool,
+        is_constant: bool,
+        attributes: Vec<Box<Node>>,
+        doc_comment: Option<std::rc::Rc<str>>,
+        line: usize,
     },
     ExpressionStatement {
         argument: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    ThrowStatement {
+        argument: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    DeferStatement {
+        argument: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    TryStatement {
+        body: Box<Node>,
+        catch_parameter: Option<Box<Node>>,
+        catch_body: Option<Box<Node>>,
+        finally_body: Option<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    MatchStatement {
+        argument: Box<Node>,
+        arms: Vec<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    MatchArm {
+        pattern: Box<Node>,
+        guard: Option<Box<Node>>,
+        body: Box<Node>,
+    },
+    ImportStatement {
+        source: std::rc::Rc<str>,
+        alias: Option<Box<Node>>,
+        names: Vec<Box<Node>>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
+    },
+    ExportStatement {
+        argument: Box<Node>,
+        attributes: Vec<Box<Node>>,
+        line: usize,
     },
     EmptyStatement,
+    Attribute {
+        name: std::rc::Rc<str>,
+        arguments: Vec<Box<Node>>,
+    },
 
     LiteralExpression {
         value: std::rc::Rc<str>,
@@ -35,6 +106,12 @@ pub enum Node {
     IdentifierExpression {
         name: std::rc::Rc<str>,
     },
+    StringExpression {
+        value: std::rc::Rc<str>,
+    },
+    InterpolatedStringExpression {
+        parts: Vec<Box<Node>>,
+    },
     InfixExpression {
         left_argument: Box<Node>,
         right_argument: Box<Node>,
@@ -44,8 +121,23 @@ pub enum Node {
         argument: Box<Node>,
         operator: super::token::Token,
     },
+    PipelineExpression {
+        argument: Box<Node>,
+        target: Box<Node>,
+    },
+    RangeExpression {
+        start: Option<Box<Node>>,
+        end: Option<Box<Node>>,
+        is_inclusive: bool,
+    },
+    ArrayExpression {
+        elements: Vec<Box<Node>>,
+    },
+    SpreadExpression {
+        argument: Box<Node>,
+    },
     TableExpression {
-        properties: Vec<(Box<Node>, Box<Node>)>,
+        properties: Vec<TableProperty>,
     },
     MemberExpression {
         target: Box<Node>,
@@ -54,21 +146,84 @@ pub enum Node {
     },
     FuncExpression {
         parameters: Vec<Box<Node>>,
+        default_arguments: Vec<Option<Box<Node>>>,
+        variadic_parameter: Option<Box<Node>>,
+        parameter_types: Vec<Option<Box<Node>>>,
+        variadic_parameter_type: Option<Box<Node>>,
+        return_type: Option<Box<Node>>,
         body: Box<Node>,
+        is_generator: bool,
+        doc_comment: Option<std::rc::Rc<str>>,
     },
     CallExpression {
         target: Box<Node>,
         arguments: Vec<Box<Node>>,
+        is_optional: bool,
+    },
+    MethodCallExpression {
+        target: Box<Node>,
+        method: Box<Node>,
+        arguments: Vec<Box<Node>>,
+    },
+    YieldExpression {
+        argument: Option<Box<Node>>,
+    },
+
+    LiteralPattern {
+        value: std::rc::Rc<str>,
+    },
+    StringPattern {
+        value: std::rc::Rc<str>,
+    },
+    IdentifierPattern {
+        name: std::rc::Rc<str>,
+    },
+    WildcardPattern,
+    ArrayPattern {
+        elements: Vec<Box<Node>>,
+    },
+    RestPattern {
+        argument: Box<Node>,
+    },
+    TablePattern {
+        properties: Vec<(Box<Node>, Box<Node>)>,
+    },
+
+    PrimitiveType {
+        name: std::rc::Rc<str>,
+    },
+    TableType {
+        properties: Vec<(std::rc::Rc<str>, Box<Node>)>,
+    },
+    FuncType {
+        parameter_types: Vec<Box<Node>>,
+        return_type: Option<Box<Node>>,
+    },
+    UnionType {
+        types: Vec<Box<Node>>,
+    },
+    OptionalType {
+        argument: Box<Node>,
     },
 }
 
+pub type TableProperty = (
+    Box<Node>,
+    Box<Node>,
+    PropertyKeyType,
+    Option<std::rc::Rc<str>>,
+);
+
 #[derive(PartialEq, PartialOrd)]
 pub enum Precedence {
     Lowest,
     Assignment,
+    Pipeline,
+    Coalescing,
     Logical,
     Bitwise,
     Comparative,
+    Range,
     Additive,
     Multiplicative,
     Prefix,
@@ -80,4 +235,12 @@ pub enum Precedence {
 pub enum MemberNotationType {
     Bracket,
     Dot,
+    OptionalBracket,
+    OptionalDot,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum PropertyKeyType {
+    Static,
+    Computed,
 }
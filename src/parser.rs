@@ -1,9 +1,15 @@
 pub struct Parser<'a> {
     lexer: &'a mut super::lexer::Lexer<'a>,
+    previous_token: super::token::Token,
     current_token: super::token::Token,
     next_token: super::token::Token,
+    current_line: usize,
+    next_line: usize,
     semicolon_skippable_next: bool,
     semicolon_skippable_now: bool,
+    doc_comment_next: Option<std::rc::Rc<str>>,
+    doc_comment_now: Option<std::rc::Rc<str>>,
+    generator_state: Option<bool>,
 }
 
 impl<'a> Parser<'a> {
@@ -11,24 +17,42 @@ impl<'a> Parser<'a> {
     pub fn new(lexer: &'a mut super::lexer::Lexer<'a>) -> Self {
         Parser {
             lexer,
+            previous_token: super::token::Token::Eof,
             current_token: super::token::Token::Eof,
             next_token: super::token::Token::Eof,
+            current_line: 1,
+            next_line: 1,
             semicolon_skippable_next: false,
             semicolon_skippable_now: false,
+            doc_comment_next: None,
+            doc_comment_now: None,
+            generator_state: None,
         }
     }
 
     fn read_token(&mut self, error_handler: &mut super::error_handler::ErrorHandler) -> Option<()> {
-        self.current_token = std::mem::take(&mut self.next_token);
+        self.previous_token = std::mem::replace(
+            &mut self.current_token,
+            std::mem::take(&mut self.next_token),
+        );
+
+        self.current_line = self.next_line;
 
         self.semicolon_skippable_now = self.semicolon_skippable_next;
         self.semicolon_skippable_next = false;
 
+        self.doc_comment_now = std::mem::take(&mut self.doc_comment_next);
+
+        let mut doc_comment_lines: Vec<std::rc::Rc<str>> = Vec::new();
+
         loop {
             let token: super::token::Token = self.lexer.next(error_handler)?;
 
+            self.next_line = self.lexer.token_line();
+
             match token {
                 super::token::Token::NewLine => self.semicolon_skippable_next = true,
+                super::token::Token::DocComment(line) => doc_comment_lines.push(line),
                 super::token::Token::SymbolRightBrace => {
                     self.semicolon_skippable_next = true;
                     self.next_token = token;
@@ -46,18 +70,48 @@ impl<'a> Parser<'a> {
             }
         }
 
+        if !doc_comment_lines.is_empty() {
+            self.doc_comment_next = Some(std::rc::Rc::from(doc_comment_lines.join("\n")));
+        }
+
         Some(())
     }
 
+    fn attach_doc_comment(
+        statement: &mut super::node::Node,
+        doc_comment: Option<std::rc::Rc<str>>,
+    ) {
+        if let super::node::Node::AssignmentStatement {
+            doc_comment: statement_doc_comment @ None,
+            ..
+        } = statement
+        {
+            *statement_doc_comment = doc_comment;
+        }
+    }
+
     fn peek_current_token(&self) -> &super::token::Token {
         &self.current_token
     }
 
+    fn peek_next_token(&self) -> &super::token::Token {
+        &self.next_token
+    }
+
+    fn is_contextual_keyword(&self, keyword: &str) -> bool {
+        match self.peek_current_token() {
+            super::token::Token::Identifier(name) => name.as_ref() == keyword,
+            _ => false,
+        }
+    }
+
     fn precedence_of_token(token: &super::token::Token) -> super::node::Precedence {
         match token {
             super::token::Token::SymbolEqual | super::token::Token::SymbolColonEqual => {
                 super::node::Precedence::Assignment
             }
+            super::token::Token::SymbolPipeGreater => super::node::Precedence::Pipeline,
+            super::token::Token::SymbolQuestionQuestion => super::node::Precedence::Coalescing,
             super::token::Token::SymbolPipePipe | super::token::Token::SymbolAmpersandAmpersand => {
                 super::node::Precedence::Logical
             }
@@ -72,6 +126,9 @@ impl<'a> Parser<'a> {
             | super::token::Token::SymbolBangEqual
             | super::token::Token::SymbolLess
             | super::token::Token::SymbolGreater => super::node::Precedence::Comparative,
+            super::token::Token::SymbolDotDot | super::token::Token::SymbolDotDotEqual => {
+                super::node::Precedence::Range
+            }
             super::token::Token::SymbolPlus | super::token::Token::SymbolMinus => {
                 super::node::Precedence::Additive
             }
@@ -79,9 +136,11 @@ impl<'a> Parser<'a> {
                 super::node::Precedence::Multiplicative
             }
             super::token::Token::SymbolLeftParenthesis => super::node::Precedence::Call,
-            super::token::Token::SymbolLeftSquareBracket | super::token::Token::SymbolDot => {
-                super::node::Precedence::Member
-            }
+            super::token::Token::SymbolLeftSquareBracket
+            | super::token::Token::SymbolQuestionLeftSquareBracket
+            | super::token::Token::SymbolDot
+            | super::token::Token::SymbolQuestionDot
+            | super::token::Token::SymbolColon => super::node::Precedence::Member,
 
             _ => super::node::Precedence::Lowest,
         }
@@ -97,7 +156,23 @@ impl<'a> Parser<'a> {
         let mut body: Vec<Box<super::node::Node>> = Vec::new();
 
         while self.peek_current_token() != &super::token::Token::Eof {
-            let statement = self.parse_statement(error_handler)?;
+            let statement = match self.peek_current_token() {
+                super::token::Token::KeywordImport => self.parse_import_statement(error_handler)?,
+                super::token::Token::KeywordExport => self.parse_export_statement(error_handler)?,
+                super::token::Token::SymbolAt => {
+                    self.parse_attributed_statement(true, error_handler)?
+                }
+                _ => self.parse_statement(error_handler)?,
+            };
+
+            if matches!(statement, super::node::Node::DeferStatement { .. }) {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(
+                    "unexpected token: KeywordDefer, only allowed inside a block".to_string(),
+                ));
+
+                return None;
+            }
+
             body.push(Box::new(statement));
         }
 
@@ -108,21 +183,318 @@ impl<'a> Parser<'a> {
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        match self.peek_current_token() {
+        let doc_comment: Option<std::rc::Rc<str>> = self.doc_comment_now.clone();
+
+        let mut statement: super::node::Node = match self.peek_current_token() {
             super::token::Token::SymbolLeftBrace => self.parse_block_statement(error_handler),
             super::token::Token::KeywordIf => self.parse_if_statement(error_handler),
-            super::token::Token::KeywordLoop => self.parse_loop_statement(error_handler),
+            super::token::Token::KeywordLoop | super::token::Token::Label(..) => {
+                self.parse_loop_statement(error_handler)
+            }
             super::token::Token::KeywordReturn => self.parse_return_statement(error_handler),
             super::token::Token::KeywordBreak => self.parse_break_statement(error_handler),
             super::token::Token::KeywordContinue => self.parse_continue_statement(error_handler),
-            _ => self.parse_assignment_statement_and_expression_statement(error_handler),
+            super::token::Token::KeywordMatch => self.parse_match_statement(error_handler),
+            super::token::Token::KeywordImport | super::token::Token::KeywordExport => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "unexpected token: {}, only allowed at the top level of a module",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                None
+            }
+            super::token::Token::KeywordConst => self.parse_const_statement(error_handler),
+            super::token::Token::KeywordThrow => self.parse_throw_statement(error_handler),
+            super::token::Token::KeywordTry => self.parse_try_statement(error_handler),
+            super::token::Token::KeywordDefer => self.parse_defer_statement(error_handler),
+            super::token::Token::SymbolAt => self.parse_attributed_statement(false, error_handler),
+            _ => self.parse_assignment_statement_and_expression_statement(false, error_handler),
+        }?;
+
+        Self::attach_doc_comment(&mut statement, doc_comment);
+
+        Some(statement)
+    }
+
+    fn parse_attributed_statement(
+        &mut self,
+        is_top_level: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let doc_comment: Option<std::rc::Rc<str>> = self.doc_comment_now.clone();
+
+        let mut attributes: Vec<Box<super::node::Node>> = Vec::new();
+
+        while self.peek_current_token() == &super::token::Token::SymbolAt {
+            let attribute: super::node::Node = self.parse_attribute(error_handler)?;
+
+            attributes.push(Box::new(attribute));
+        }
+
+        let mut statement: super::node::Node = match self.peek_current_token() {
+            super::token::Token::KeywordImport if is_top_level => {
+                self.parse_import_statement(error_handler)?
+            }
+            super::token::Token::KeywordExport if is_top_level => {
+                self.parse_export_statement(error_handler)?
+            }
+            _ => self.parse_statement(error_handler)?,
+        };
+
+        match &mut statement {
+            super::node::Node::ExportStatement { argument, .. } => {
+                Self::attach_doc_comment(argument, doc_comment);
+            }
+            _ => Self::attach_doc_comment(&mut statement, doc_comment),
+        }
+
+        if let super::node::Node::BlockStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::IfStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::LoopStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ReturnStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::BreakStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ContinueStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::AssignmentStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ExpressionStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ThrowStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::DeferStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::TryStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::MatchStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ImportStatement {
+            attributes: statement_attributes,
+            ..
+        }
+        | super::node::Node::ExportStatement {
+            attributes: statement_attributes,
+            ..
+        } = &mut statement
+        {
+            *statement_attributes = attributes;
+        }
+
+        Some(statement)
+    }
+
+    fn parse_attribute(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let name: std::rc::Rc<str> = match self.peek_current_token() {
+            super::token::Token::Identifier(name) => name.clone(),
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: Identifier, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        };
+
+        self.read_token(error_handler)?;
+
+        let arguments: Vec<Box<super::node::Node>> = if self.peek_current_token()
+            == &super::token::Token::SymbolLeftParenthesis
+            && !self.semicolon_skippable_now
+        {
+            self.parse_call_arguments(error_handler)?
+        } else {
+            Vec::new()
+        };
+
+        Some(super::node::Node::Attribute { name, arguments })
+    }
+
+    fn parse_import_statement(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
+        self.read_token(error_handler)?;
+
+        let mut names: Vec<Box<super::node::Node>> = Vec::new();
+
+        if self.peek_current_token() == &super::token::Token::SymbolLeftBrace {
+            self.read_token(error_handler)?;
+
+            while self.peek_current_token() != &super::token::Token::SymbolRightBrace {
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let name: super::node::Node = self.parse_identifier_expression(error_handler)?;
+
+                names.push(Box::new(name));
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if self.peek_current_token() != &super::token::Token::SymbolRightBrace {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+
+            self.read_token(error_handler)?;
+
+            if !self.is_contextual_keyword("from") {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected from, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+
+            self.read_token(error_handler)?;
+        }
+
+        let source: std::rc::Rc<str> = match self.peek_current_token() {
+            super::token::Token::String(value) => value.clone(),
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: String, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        };
+
+        self.read_token(error_handler)?;
+
+        let alias: Option<Box<super::node::Node>> =
+            if names.is_empty() && self.is_contextual_keyword("as") {
+                self.read_token(error_handler)?;
+
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                Some(Box::new(self.parse_identifier_expression(error_handler)?))
+            } else {
+                None
+            };
+
+        if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+            self.read_token(error_handler)?;
+        } else if !self.semicolon_skippable_now {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolSemicolon, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        Some(super::node::Node::ImportStatement {
+            source,
+            alias,
+            names,
+            attributes: Vec::new(),
+            line,
+        })
+    }
+
+    fn parse_export_statement(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
+        let doc_comment: Option<std::rc::Rc<str>> = self.doc_comment_now.clone();
+
+        self.read_token(error_handler)?;
+
+        let mut argument: super::node::Node = self.parse_statement(error_handler)?;
+
+        Self::attach_doc_comment(&mut argument, doc_comment);
+
+        if !matches!(
+            argument,
+            super::node::Node::AssignmentStatement {
+                is_decleration: true,
+                ..
+            }
+        ) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(
+                "expected declaration after export".to_string(),
+            ));
+
+            return None;
         }
+
+        Some(super::node::Node::ExportStatement {
+            argument: Box::new(argument),
+            attributes: Vec::new(),
+            line,
+        })
     }
 
     fn parse_block_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
         self.read_token(error_handler)?;
 
         let mut body: Vec<Box<super::node::Node>> = Vec::new();
@@ -142,15 +514,110 @@ impl<'a> Parser<'a> {
             body.push(Box::new(statement));
         }
 
+        let value: Option<Box<super::node::Node>> =
+            if self.previous_token != super::token::Token::SymbolSemicolon {
+                match body.pop().map(|statement| *statement) {
+                    Some(super::node::Node::ExpressionStatement {
+                        argument,
+                        attributes,
+                        ..
+                    }) if attributes.is_empty() => Some(argument),
+                    Some(
+                        statement @ (super::node::Node::IfStatement { .. }
+                        | super::node::Node::MatchStatement { .. }
+                        | super::node::Node::LoopStatement { .. }
+                        | super::node::Node::BlockStatement { .. }),
+                    ) if Self::has_value(&statement) => Some(Box::new(statement)),
+                    Some(statement) => {
+                        body.push(Box::new(statement));
+                        None
+                    }
+                    None => None,
+                }
+            } else {
+                None
+            };
+
         self.read_token(error_handler)?;
 
-        Some(super::node::Node::BlockStatement { body })
+        Some(super::node::Node::BlockStatement {
+            body,
+            value,
+            attributes: Vec::new(),
+            line,
+        })
+    }
+
+    fn has_value(node: &super::node::Node) -> bool {
+        match node {
+            super::node::Node::BlockStatement { value, .. } => value.is_some(),
+            super::node::Node::IfStatement {
+                consequent_body,
+                alternate_body,
+                ..
+            } => Self::has_value(consequent_body) || Self::has_value(alternate_body),
+            super::node::Node::MatchStatement { arms, .. } => arms.iter().any(|arm| {
+                matches!(arm.as_ref(), super::node::Node::MatchArm { body, .. } if Self::has_value(body))
+            }),
+            super::node::Node::LoopStatement { label, body, .. } => {
+                Self::breaks_with_value(body, label, false)
+            }
+            super::node::Node::EmptyStatement => false,
+            _ => true,
+        }
+    }
+
+    fn breaks_with_value(
+        node: &super::node::Node,
+        loop_label: &Option<std::rc::Rc<str>>,
+        is_nested: bool,
+    ) -> bool {
+        match node {
+            super::node::Node::BreakStatement {
+                label,
+                argument: Some(..),
+                ..
+            } => match label {
+                Some(label) => loop_label.as_ref() == Some(label),
+                None => !is_nested,
+            },
+            super::node::Node::BlockStatement { body, value, .. } => body
+                .iter()
+                .chain(value.iter())
+                .any(|statement| Self::breaks_with_value(statement, loop_label, is_nested)),
+            super::node::Node::IfStatement {
+                consequent_body,
+                alternate_body,
+                ..
+            } => {
+                Self::breaks_with_value(consequent_body, loop_label, is_nested)
+                    || Self::breaks_with_value(alternate_body, loop_label, is_nested)
+            }
+            super::node::Node::MatchStatement { arms, .. } => arms.iter().any(|arm| {
+                matches!(arm.as_ref(), super::node::Node::MatchArm { body, .. } if Self::breaks_with_value(body, loop_label, is_nested))
+            }),
+            super::node::Node::TryStatement {
+                body,
+                catch_body,
+                finally_body,
+                ..
+            } => std::iter::once(body)
+                .chain(catch_body.iter())
+                .chain(finally_body.iter())
+                .any(|body| Self::breaks_with_value(body, loop_label, is_nested)),
+            super::node::Node::LoopStatement { body, .. } => {
+                Self::breaks_with_value(body, loop_label, true)
+            }
+            _ => false,
+        }
     }
 
     fn parse_if_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
         self.read_token(error_handler)?;
 
         let argument: super::node::Node =
@@ -191,6 +658,8 @@ impl<'a> Parser<'a> {
             argument: Box::new(argument),
             consequent_body: Box::new(consequent_body),
             alternate_body: Box::new(alternate_body),
+            attributes: Vec::new(),
+            line,
         })
     }
 
@@ -198,6 +667,37 @@ impl<'a> Parser<'a> {
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
+        let label: Option<std::rc::Rc<str>> = match self.peek_current_token() {
+            super::token::Token::Label(name) => Some(name.clone()),
+            _ => None,
+        };
+
+        if label.is_some() {
+            self.read_token(error_handler)?;
+
+            if self.peek_current_token() != &super::token::Token::SymbolColon {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolColon, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+
+            self.read_token(error_handler)?;
+
+            if self.peek_current_token() != &super::token::Token::KeywordLoop {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: KeywordLoop, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        }
+
         self.read_token(error_handler)?;
 
         if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
@@ -211,33 +711,81 @@ impl<'a> Parser<'a> {
 
         let body: super::node::Node = self.parse_block_statement(error_handler)?;
 
+        if let super::node::Node::BlockStatement { body, .. } = &body
+            && body.iter().any(|statement| {
+                matches!(statement.as_ref(), super::node::Node::DeferStatement { .. })
+            })
+        {
+            error_handler.add_warning(super::error_handler::Warning::LintWarning(
+                "defer at the top level of a loop body runs at the end of every iteration"
+                    .to_string(),
+            ));
+        }
+
         Some(super::node::Node::LoopStatement {
+            label,
             body: Box::new(body),
+            attributes: Vec::new(),
+            line,
         })
     }
 
+    fn parse_label_reference(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Option<std::rc::Rc<str>>> {
+        let label: std::rc::Rc<str> = match self.peek_current_token() {
+            super::token::Token::Label(name) => name.clone(),
+            _ => return Some(None),
+        };
+
+        self.read_token(error_handler)?;
+
+        Some(Some(label))
+    }
+
     fn parse_return_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        self.read_token(error_handler)?;
+        let line: usize = self.current_line;
 
-        let argument: super::node::Node =
-            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+        self.read_token(error_handler)?;
 
-        if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
-            self.read_token(error_handler)?;
-        } else if !self.semicolon_skippable_now {
-            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                "expected token: SymbolSemicolon, but got token: {}",
-                self.peek_current_token().type_as_string()
-            )));
+        let mut arguments: Vec<Box<super::node::Node>> = Vec::new();
+
+        if self.peek_current_token() != &super::token::Token::SymbolSemicolon
+            && !self.semicolon_skippable_now
+        {
+            loop {
+                let argument: super::node::Node =
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                arguments.push(Box::new(argument));
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+            self.read_token(error_handler)?;
+        } else if !self.semicolon_skippable_now {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolSemicolon, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
             return None;
         }
 
         Some(super::node::Node::ReturnStatement {
-            argument: Box::new(argument),
+            arguments,
+            attributes: Vec::new(),
+            line,
         })
     }
 
@@ -245,8 +793,24 @@ impl<'a> Parser<'a> {
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
         self.read_token(error_handler)?;
 
+        let label: Option<std::rc::Rc<str>> = self.parse_label_reference(error_handler)?;
+
+        let argument: Option<Box<super::node::Node>> = if self.peek_current_token()
+            != &super::token::Token::SymbolSemicolon
+            && !self.semicolon_skippable_now
+        {
+            Some(Box::new(self.parse_expression(
+                &super::node::Precedence::Lowest,
+                error_handler,
+            )?))
+        } else {
+            None
+        };
+
         if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
             self.read_token(error_handler)?;
         } else if !self.semicolon_skippable_now {
@@ -258,15 +822,24 @@ impl<'a> Parser<'a> {
             return None;
         }
 
-        Some(super::node::Node::BreakStatement {})
+        Some(super::node::Node::BreakStatement {
+            label,
+            argument,
+            attributes: Vec::new(),
+            line,
+        })
     }
 
     fn parse_continue_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
+
         self.read_token(error_handler)?;
 
+        let label: Option<std::rc::Rc<str>> = self.parse_label_reference(error_handler)?;
+
         if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
             self.read_token(error_handler)?;
         } else if !self.semicolon_skippable_now {
@@ -278,313 +851,410 @@ impl<'a> Parser<'a> {
             return None;
         }
 
-        Some(super::node::Node::ContinueStatement {})
+        Some(super::node::Node::ContinueStatement {
+            label,
+            attributes: Vec::new(),
+            line,
+        })
     }
 
-    fn parse_assignment_statement_and_expression_statement(
+    fn parse_throw_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        let argument: super::node::Node =
-            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+        let line: usize = self.current_line;
 
-        match self.peek_current_token() {
-            super::token::Token::SymbolEqual => {
-                self.read_token(error_handler)?;
-
-                let assignment_argument: super::node::Node =
-                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+        self.read_token(error_handler)?;
 
-                if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
-                    self.read_token(error_handler)?;
-                } else if !self.semicolon_skippable_now {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolSemicolon, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+        let argument: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
 
-                    return None;
-                }
+        if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+            self.read_token(error_handler)?;
+        } else if !self.semicolon_skippable_now {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolSemicolon, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
-                Some(super::node::Node::AssignmentStatement {
-                    target: Box::new(argument),
-                    argument: Box::new(assignment_argument),
-                    is_decleration: false,
-                })
-            }
-            super::token::Token::SymbolColonEqual => {
-                self.read_token(error_handler)?;
+            return None;
+        }
 
-                let assignment_argument: super::node::Node =
-                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+        Some(super::node::Node::ThrowStatement {
+            argument: Box::new(argument),
+            attributes: Vec::new(),
+            line,
+        })
+    }
 
-                if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
-                    self.read_token(error_handler)?;
-                } else if !self.semicolon_skippable_now {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolSemicolon, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+    fn parse_defer_statement(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
 
-                    return None;
-                }
+        self.read_token(error_handler)?;
 
-                Some(super::node::Node::AssignmentStatement {
-                    target: Box::new(argument),
-                    argument: Box::new(assignment_argument),
-                    is_decleration: true,
-                })
-            }
-            _ => {
-                if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
-                    self.read_token(error_handler)?;
-                } else if !self.semicolon_skippable_now {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolSemicolon, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+        let argument: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
 
-                    return None;
-                }
+        if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+            self.read_token(error_handler)?;
+        } else if !self.semicolon_skippable_now {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolSemicolon, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
-                Some(super::node::Node::ExpressionStatement {
-                    argument: Box::new(argument),
-                })
-            }
+            return None;
         }
+
+        Some(super::node::Node::DeferStatement {
+            argument: Box::new(argument),
+            attributes: Vec::new(),
+            line,
+        })
     }
 
-    fn parse_expression(
+    fn parse_try_statement(
         &mut self,
-        precedence: &super::node::Precedence,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        let mut left_argument: super::node::Node = match self.peek_current_token() {
-            super::token::Token::Literal(..) => self.parse_literal_expression(error_handler),
-            super::token::Token::Identifier(..) => self.parse_identifier_expression(error_handler),
-            super::token::Token::KeywordTable => self.parse_table_expression(error_handler),
-            super::token::Token::KeywordFunc => self.parse_func_expression(error_handler),
-            super::token::Token::SymbolLeftParenthesis => {
-                self.parse_grouped_expression(error_handler)
+        let line: usize = self.current_line;
+
+        self.read_token(error_handler)?;
+
+        if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolLeftBrace, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let body: super::node::Node = self.parse_block_statement(error_handler)?;
+
+        let mut catch_parameter: Option<Box<super::node::Node>> = None;
+        let mut catch_body: Option<Box<super::node::Node>> = None;
+        let mut finally_body: Option<Box<super::node::Node>> = None;
+
+        if self.peek_current_token() == &super::token::Token::KeywordCatch {
+            self.read_token(error_handler)?;
+
+            if matches!(
+                self.peek_current_token(),
+                super::token::Token::Identifier(..)
+            ) {
+                catch_parameter = Some(Box::new(self.parse_identifier_expression(error_handler)?));
             }
-            super::token::Token::SymbolMinus
-            | super::token::Token::SymbolBang => self.parse_prefix_expression(error_handler),
-            _ => {
+
+            if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
                 error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                    "unexpected token: {}",
-                    self.peek_current_token().type_as_string(),
+                    "expected token: SymbolLeftBrace, but got token: {}",
+                    self.peek_current_token().type_as_string()
                 )));
 
                 return None;
             }
-        }?;
 
-        while precedence < &Self::precedence_of_token(self.peek_current_token()) {
-            match self.peek_current_token() {
-                super::token::Token::SymbolPlus
-                | super::token::Token::SymbolMinus
-                | super::token::Token::SymbolAsterisk
-                | super::token::Token::SymbolSlash
-                | super::token::Token::SymbolPipe
-                | super::token::Token::SymbolAmpersand
-                | super::token::Token::SymbolCaret
-                | super::token::Token::SymbolLessLess
-                | super::token::Token::SymbolGreaterGreater
-                | super::token::Token::SymbolLess
-                | super::token::Token::SymbolGreater
-                | super::token::Token::SymbolEqualEqual
-                | super::token::Token::SymbolLessEqual
-                | super::token::Token::SymbolGreaterEqual
-                | super::token::Token::SymbolBangEqual
-                | super::token::Token::SymbolPipePipe
-                | super::token::Token::SymbolAmpersandAmpersand => {
-                    left_argument = self.parse_infix_expression(left_argument, error_handler)?;
-                }
-                super::token::Token::SymbolLeftParenthesis => {
-                    left_argument = self.parse_call_expression(left_argument, error_handler)?;
-                }
-                super::token::Token::SymbolLeftSquareBracket | super::token::Token::SymbolDot => {
-                    left_argument = self.parse_member_expression(left_argument, error_handler)?;
-                }
-                _ => break,
-            }
+            catch_body = Some(Box::new(self.parse_block_statement(error_handler)?));
         }
 
-        Some(left_argument)
-    }
+        if self.peek_current_token() == &super::token::Token::KeywordFinally {
+            self.read_token(error_handler)?;
 
-    fn parse_literal_expression(
-        &mut self,
-        error_handler: &mut super::error_handler::ErrorHandler,
-    ) -> Option<super::node::Node> {
-        let literal_expression: super::node::Node = match self.peek_current_token() {
-            super::token::Token::Literal(value) => super::node::Node::LiteralExpression {
-                value: value.clone(),
-            },
-            _ => unreachable!(),
-        };
+            if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolLeftBrace, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
 
-        self.read_token(error_handler)?;
+                return None;
+            }
 
-        Some(literal_expression)
-    }
+            finally_body = Some(Box::new(self.parse_block_statement(error_handler)?));
+        }
 
-    fn parse_identifier_expression(
-        &mut self,
-        error_handler: &mut super::error_handler::ErrorHandler,
-    ) -> Option<super::node::Node> {
-        let identifier_expression: super::node::Node = match self.peek_current_token() {
-            super::token::Token::Identifier(name) => super::node::Node::IdentifierExpression {
-                name: name.clone(),
-            },
-            _ => unreachable!(),
-        };
+        if catch_body.is_none() && finally_body.is_none() {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: KeywordCatch or token: KeywordFinally, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
-        self.read_token(error_handler)?;
+            return None;
+        }
 
-        Some(identifier_expression)
+        Some(super::node::Node::TryStatement {
+            body: Box::new(body),
+            catch_parameter,
+            catch_body,
+            finally_body,
+            attributes: Vec::new(),
+            line,
+        })
     }
 
-    fn parse_table_expression(
+    fn parse_match_statement(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        self.read_token(error_handler)?;
+        let line: usize = self.current_line;
 
-        let mut properties: Vec<(Box<super::node::Node>, Box<super::node::Node>)> = Vec::new();
+        self.read_token(error_handler)?;
 
-        if self.peek_current_token() == &super::token::Token::SymbolLeftSquareBracket {
-            self.read_token(error_handler)?;
+        let argument: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
 
-            loop {
-                let key: super::node::Node = match self.peek_current_token() {
-                    super::token::Token::Literal(..) => {
-                        self.parse_literal_expression(error_handler)?
-                    }
-                    super::token::Token::Identifier(..) => {
-                        self.parse_identifier_expression(error_handler)?
-                    }
-                    _ => {
-                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                            "expected token: Literal or token: Identifier, but got token: {}",
-                            self.peek_current_token().type_as_string()
-                        )));
+        if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolLeftBrace, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
-                        return None;
-                    }
-                };
+            return None;
+        }
 
-                if self.peek_current_token() != &super::token::Token::SymbolColon {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolColon, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+        self.read_token(error_handler)?;
 
-                    return None;
-                }
+        let mut arms: Vec<Box<super::node::Node>> = Vec::new();
 
-                self.read_token(error_handler)?;
+        while self.peek_current_token() != &super::token::Token::SymbolRightBrace {
+            let pattern: super::node::Node = self.parse_pattern(error_handler)?;
 
-                let value: super::node::Node =
-                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+            let guard: Option<Box<super::node::Node>> =
+                if self.peek_current_token() == &super::token::Token::KeywordIf {
+                    self.read_token(error_handler)?;
 
-                properties.push((Box::new(key), Box::new(value)));
+                    Some(Box::new(self.parse_expression(
+                        &super::node::Precedence::Lowest,
+                        error_handler,
+                    )?))
+                } else {
+                    None
+                };
 
-                if self.peek_current_token() == &super::token::Token::SymbolComma {
-                    self.read_token(error_handler)?;
-                } else if self.peek_current_token()
-                    == &super::token::Token::SymbolRightSquareBracket
-                {
-                    self.read_token(error_handler)?;
-                    break;
-                } else {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolComma, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+            if self.peek_current_token() != &super::token::Token::SymbolEqualGreater {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolEqualGreater, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
 
-                    return None;
+                return None;
+            }
+
+            self.read_token(error_handler)?;
+
+            let body: super::node::Node =
+                self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+            arms.push(Box::new(super::node::Node::MatchArm {
+                pattern: Box::new(pattern),
+                guard,
+                body: Box::new(body),
+            }));
+
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else if !self.semicolon_skippable_now
+                && self.previous_token != super::token::Token::SymbolRightBrace
+            {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolComma, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        Self::check_match_arms(&arms, error_handler);
+
+        Some(super::node::Node::MatchStatement {
+            argument: Box::new(argument),
+            arms,
+            attributes: Vec::new(),
+            line,
+        })
+    }
+
+    fn check_match_arms(
+        arms: &[Box<super::node::Node>],
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) {
+        let mut matched_values: Vec<std::rc::Rc<str>> = Vec::new();
+        let mut has_catch_all: bool = false;
+        let mut has_all_values: bool = false;
+
+        for arm in arms {
+            let super::node::Node::MatchArm { pattern, guard, .. } = arm.as_ref() else {
+                continue;
+            };
+
+            if has_catch_all {
+                error_handler.add_warning(super::error_handler::Warning::LintWarning(
+                    "unreachable match arm after catch-all pattern".to_string(),
+                ));
+
+                continue;
+            }
+
+            if has_all_values {
+                error_handler.add_warning(super::error_handler::Warning::LintWarning(
+                    "unreachable match arm: true, false and none are already matched".to_string(),
+                ));
+
+                continue;
+            }
+
+            let matched_value: Option<std::rc::Rc<str>> = match pattern.as_ref() {
+                super::node::Node::LiteralPattern { value } => Some(value.clone()),
+                super::node::Node::StringPattern { value } => {
+                    Some(std::rc::Rc::from(format!("\"{value}\"")))
+                }
+                _ => None,
+            };
+
+            if let Some(value) = matched_value {
+                if matched_values.contains(&value) {
+                    error_handler.add_warning(super::error_handler::Warning::LintWarning(format!(
+                        "unreachable match arm: {value} is already matched"
+                    )));
+                } else if guard.is_none() {
+                    matched_values.push(value);
+
+                    has_all_values = ["true", "false", "none"].iter().all(|value| {
+                        matched_values
+                            .iter()
+                            .any(|matched| matched.as_ref() == *value)
+                    });
                 }
+            } else if matches!(
+                pattern.as_ref(),
+                super::node::Node::IdentifierPattern { .. } | super::node::Node::WildcardPattern
+            ) && guard.is_none()
+            {
+                has_catch_all = true;
             }
         }
 
-        Some(super::node::Node::TableExpression { properties })
+        let is_boolean_match: bool = !matched_values.is_empty()
+            && matched_values
+                .iter()
+                .all(|value| matches!(value.as_ref(), "true" | "false" | "none"))
+            && matched_values
+                .iter()
+                .any(|value| matches!(value.as_ref(), "true" | "false"));
+
+        if !has_catch_all && is_boolean_match {
+            for value in ["true", "false"] {
+                if !matched_values
+                    .iter()
+                    .any(|matched| matched.as_ref() == value)
+                {
+                    error_handler.add_warning(super::error_handler::Warning::LintWarning(format!(
+                        "non-exhaustive match: missing pattern: {value}"
+                    )));
+                }
+            }
+        }
     }
 
-    fn parse_func_expression(
+    fn parse_pattern(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
-        self.read_token(error_handler)?;
+        match self.peek_current_token() {
+            super::token::Token::Literal(value) => {
+                let pattern: super::node::Node = super::node::Node::LiteralPattern {
+                    value: value.clone(),
+                };
 
-        let mut parameters: Vec<Box<super::node::Node>> = Vec::new();
+                self.read_token(error_handler)?;
 
-        if matches!(
-            self.peek_current_token(),
-            super::token::Token::Identifier(..)
-        ) {
-            loop {
-                if !matches!(
-                    self.peek_current_token(),
-                    super::token::Token::Identifier(..)
-                ) {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: Identifier, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+                Some(pattern)
+            }
+            super::token::Token::String(value) => {
+                let pattern: super::node::Node = super::node::Node::StringPattern {
+                    value: value.clone(),
+                };
 
-                    return None;
-                }
+                self.read_token(error_handler)?;
 
-                let parameter: super::node::Node =
-                    self.parse_identifier_expression(error_handler)?;
+                Some(pattern)
+            }
+            super::token::Token::SymbolMinus => {
+                self.read_token(error_handler)?;
 
-                parameters.push(Box::new(parameter));
+                let pattern: super::node::Node = match self.peek_current_token() {
+                    super::token::Token::Literal(value)
+                        if value.starts_with(|c: char| c.is_ascii_digit()) =>
+                    {
+                        super::node::Node::LiteralPattern {
+                            value: std::rc::Rc::from(format!("-{value}")),
+                        }
+                    }
+                    super::token::Token::Literal(value) => {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                            "invalid pattern: -{value}"
+                        )));
 
-                if self.peek_current_token() == &super::token::Token::SymbolComma {
-                    self.read_token(error_handler)?;
-                } else if self.peek_current_token() == &super::token::Token::SymbolLeftBrace {
-                    break;
+                        return None;
+                    }
+                    _ => {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                            "expected token: Literal, but got token: {}",
+                            self.peek_current_token().type_as_string()
+                        )));
+
+                        return None;
+                    }
+                };
+
+                self.read_token(error_handler)?;
+
+                Some(pattern)
+            }
+            super::token::Token::Identifier(name) => {
+                let pattern: super::node::Node = if name.as_ref() == "_" {
+                    super::node::Node::WildcardPattern
                 } else {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolComma, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+                    super::node::Node::IdentifierPattern { name: name.clone() }
+                };
 
-                    return None;
-                }
+                self.read_token(error_handler)?;
+
+                Some(pattern)
             }
-        } else {
-            if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
+            super::token::Token::KeywordTable => self.parse_table_pattern(error_handler),
+            super::token::Token::SymbolLeftSquareBracket => {
+                let elements: super::node::Node = self.parse_array_expression(error_handler)?;
+                let mut bound_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+                Self::parse_destructuring_pattern(elements, true, &mut bound_names, error_handler)
+            }
+            _ => {
                 error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                    "expected token: SymbolLeftBrace, but got token: {}",
-                    self.peek_current_token().type_as_string()
+                    "unexpected token: {}",
+                    self.peek_current_token().type_as_string(),
                 )));
 
-                return None;
+                None
             }
         }
-
-        let body: super::node::Node = self.parse_block_statement(error_handler)?;
-
-        Some(super::node::Node::FuncExpression {
-            parameters,
-            body: Box::new(body),
-        })
     }
 
-    fn parse_grouped_expression(
+    fn parse_table_pattern(
         &mut self,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
         self.read_token(error_handler)?;
 
-        let expression: super::node::Node =
-            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
-
-        if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+        if self.peek_current_token() != &super::token::Token::SymbolLeftSquareBracket {
             error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                "expected token: SymbolRightParenthesis, but got token: {}",
+                "expected token: SymbolLeftSquareBracket, but got token: {}",
                 self.peek_current_token().type_as_string()
             )));
 
@@ -593,141 +1263,2845 @@ impl<'a> Parser<'a> {
 
         self.read_token(error_handler)?;
 
-        Some(expression)
-    }
+        let mut properties: Vec<(Box<super::node::Node>, Box<super::node::Node>)> = Vec::new();
 
-    fn parse_prefix_expression(
-        &mut self,
-        error_handler: &mut super::error_handler::ErrorHandler,
-    ) -> Option<super::node::Node> {
-        let operator: super::token::Token = self.peek_current_token().clone();
+        while self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+            let key: super::node::Node = match self.peek_current_token() {
+                super::token::Token::Literal(..) => self.parse_literal_expression(error_handler)?,
+                super::token::Token::Identifier(..) => {
+                    self.parse_identifier_expression(error_handler)?
+                }
+                _ => {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Literal or token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
 
-        self.read_token(error_handler)?;
+                    return None;
+                }
+            };
 
-        let argument: super::node::Node =
-            self.parse_expression(&super::node::Precedence::Prefix, error_handler)?;
+            let value: super::node::Node =
+                if self.peek_current_token() == &super::token::Token::SymbolColon {
+                    self.read_token(error_handler)?;
 
-        Some(super::node::Node::PrefixExpression {
-            argument: Box::new(argument),
-            operator,
-        })
-    }
+                    self.parse_pattern(error_handler)?
+                } else if let super::node::Node::IdentifierExpression { name } = &key {
+                    super::node::Node::IdentifierPattern { name: name.clone() }
+                } else {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolColon, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
 
-    fn parse_infix_expression(
-        &mut self,
-        left_argument: super::node::Node,
-        error_handler: &mut super::error_handler::ErrorHandler,
-    ) -> Option<super::node::Node> {
-        let operator: super::token::Token = self.peek_current_token().clone();
+                    return None;
+                };
 
-        self.read_token(error_handler)?;
+            properties.push((Box::new(key), Box::new(value)));
 
-        let precedence: super::node::Precedence = Self::precedence_of_token(&operator);
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolComma, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
 
-        let right_argument: super::node::Node =
-            self.parse_expression(&precedence, error_handler)?;
+                return None;
+            }
+        }
 
-        Some(super::node::Node::InfixExpression {
-            left_argument: Box::new(left_argument),
-            right_argument: Box::new(right_argument),
-            operator,
-        })
+        self.read_token(error_handler)?;
+
+        Some(super::node::Node::TablePattern { properties })
     }
 
-    fn parse_call_expression(
+    fn parse_const_statement(
         &mut self,
-        target: super::node::Node,
         error_handler: &mut super::error_handler::ErrorHandler,
     ) -> Option<super::node::Node> {
         self.read_token(error_handler)?;
 
-        let mut arguments: Vec<Box<super::node::Node>> = Vec::new();
+        self.parse_assignment_statement_and_expression_statement(true, error_handler)
+    }
 
-        if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
-            loop {
-                let argument: super::node::Node =
-                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+    fn parse_assignment_statement_and_expression_statement(
+        &mut self,
+        is_constant: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let line: usize = self.current_line;
 
-                arguments.push(Box::new(argument));
+        let mut targets: Vec<Box<super::node::Node>> = Vec::new();
+        let mut type_annotations: Vec<Option<Box<super::node::Node>>> = Vec::new();
 
-                if self.peek_current_token() == &super::token::Token::SymbolComma {
-                    self.read_token(error_handler)?;
-                } else if self.peek_current_token() == &super::token::Token::SymbolRightParenthesis
-                {
-                    break;
-                } else {
-                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolComma, but got token: {}",
-                        self.peek_current_token().type_as_string()
-                    )));
+        loop {
+            let (target, type_annotation): (super::node::Node, Option<Box<super::node::Node>>) = if matches!(
+                self.peek_current_token(),
+                super::token::Token::Identifier(..)
+            )
+                && self.peek_next_token() == &super::token::Token::SymbolColon
+            {
+                self.parse_annotated_target(error_handler)?
+            } else {
+                (
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?,
+                    None,
+                )
+            };
 
-                    return None;
-                }
+            targets.push(Box::new(target));
+            type_annotations.push(type_annotation);
+
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else {
+                break;
             }
         }
 
-        self.read_token(error_handler)?;
+        if is_constant && self.peek_current_token() != &super::token::Token::SymbolEqual {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolEqual, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
 
-        Some(super::node::Node::CallExpression {
-            target: Box::new(target),
-            arguments,
-        })
-    }
+            return None;
+        }
 
-    fn parse_member_expression(
-        &mut self,
-        target: super::node::Node,
-        error_handler: &mut super::error_handler::ErrorHandler,
-    ) -> Option<super::node::Node> {
         match self.peek_current_token() {
-            &super::token::Token::SymbolLeftSquareBracket => {
+            super::token::Token::SymbolEqual | super::token::Token::SymbolColonEqual => {
+                let is_decleration: bool = is_constant
+                    || self.peek_current_token() == &super::token::Token::SymbolColonEqual;
+
+                if !is_decleration && type_annotations.iter().any(Option::is_some) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(
+                        "type annotations are only allowed on declarations".to_string(),
+                    ));
+
+                    return None;
+                }
+
                 self.read_token(error_handler)?;
 
-                let argument: super::node::Node =
-                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+                let mut bound_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+                let targets: Vec<Box<super::node::Node>> = targets
+                    .into_iter()
+                    .map(|target| {
+                        Self::parse_assignment_target(
+                            *target,
+                            is_decleration,
+                            &mut bound_names,
+                            error_handler,
+                        )
+                        .map(Box::new)
+                    })
+                    .collect::<Option<_>>()?;
+
+                let mut arguments: Vec<Box<super::node::Node>> = Vec::new();
+
+                loop {
+                    let argument: super::node::Node =
+                        self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                    arguments.push(Box::new(argument));
+
+                    if self.peek_current_token() == &super::token::Token::SymbolComma {
+                        self.read_token(error_handler)?;
+                    } else {
+                        break;
+                    }
+                }
 
-                if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                if arguments.len() > 1 && arguments.len() != targets.len() {
                     error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: SymbolRightSquareBracket, but got token: {}",
-                        self.peek_current_token().type_as_string()
+                        "expected {} assignment values, but got {}",
+                        targets.len(),
+                        arguments.len()
                     )));
 
                     return None;
                 }
 
-                self.read_token(error_handler)?;
-
-                Some(super::node::Node::MemberExpression {
-                    target: Box::new(target),
-                    argument: Box::new(argument),
-                    notation_type: super::node::MemberNotationType::Bracket,
+                if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+                    self.read_token(error_handler)?;
+                } else if !self.semicolon_skippable_now {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolSemicolon, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                Some(super::node::Node::AssignmentStatement {
+                    targets,
+                    arguments,
+                    type_annotations,
+                    is_decleration,
+                    is_constant,
+                    attributes: Vec::new(),
+                    doc_comment: None,
+                    line,
                 })
             }
-            &super::token::Token::SymbolDot => {
-                self.read_token(error_handler)?;
+            _ => {
+                if targets.len() > 1 || type_annotations.iter().any(Option::is_some) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolEqual or token: SymbolColonEqual, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
 
-                if !matches!(
-                    self.peek_current_token(),
-                    &super::token::Token::Identifier(..)
-                ) {
+                    return None;
+                }
+
+                if self.peek_current_token() == &super::token::Token::SymbolSemicolon {
+                    self.read_token(error_handler)?;
+                } else if !self.semicolon_skippable_now {
                     error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
-                        "expected token: Identifier, but got token: {}",
+                        "expected token: SymbolSemicolon, but got token: {}",
                         self.peek_current_token().type_as_string()
                     )));
 
                     return None;
                 }
 
-                let argument: super::node::Node =
-                    self.parse_identifier_expression(error_handler)?;
+                Some(super::node::Node::ExpressionStatement {
+                    argument: targets.pop()?,
+                    attributes: Vec::new(),
+                    line,
+                })
+            }
+        }
+    }
 
-                Some(super::node::Node::MemberExpression {
-                    target: Box::new(target),
-                    argument: Box::new(argument),
-                    notation_type: super::node::MemberNotationType::Dot,
+    fn parse_annotated_target(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<(super::node::Node, Option<Box<super::node::Node>>)> {
+        let target: super::node::Node = self.parse_identifier_expression(error_handler)?;
+
+        self.read_token(error_handler)?;
+
+        if matches!(
+            self.peek_current_token(),
+            super::token::Token::Identifier(..)
+        ) && self.peek_next_token() == &super::token::Token::SymbolLeftParenthesis
+        {
+            let method_call_expression: super::node::Node =
+                self.parse_method_call(target, error_handler)?;
+
+            return Some((
+                self.parse_expression_from(
+                    method_call_expression,
+                    &super::node::Precedence::Lowest,
+                    error_handler,
+                )?,
+                None,
+            ));
+        }
+
+        let type_annotation: super::node::Node = self.parse_type(error_handler)?;
+
+        Some((target, Some(Box::new(type_annotation))))
+    }
+
+    fn parse_assignment_target(
+        target: super::node::Node,
+        is_decleration: bool,
+        bound_names: &mut Vec<std::rc::Rc<str>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        match target {
+            super::node::Node::IdentifierExpression { name } => {
+                if name.as_ref() == "_" {
+                    return Some(super::node::Node::WildcardPattern);
+                }
+
+                Self::bind_name(&name, is_decleration, bound_names, error_handler)?;
+
+                Some(super::node::Node::IdentifierExpression { name })
+            }
+            super::node::Node::MemberExpression {
+                notation_type:
+                    super::node::MemberNotationType::Bracket | super::node::MemberNotationType::Dot,
+                ..
+            } if !is_decleration => Some(target),
+            super::node::Node::ArrayExpression { .. }
+            | super::node::Node::TableExpression { .. } => Self::parse_destructuring_pattern(
+                target,
+                is_decleration,
+                bound_names,
+                error_handler,
+            ),
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(
+                    "invalid assignment target".to_string(),
+                ));
+
+                None
+            }
+        }
+    }
+
+    fn parse_destructuring_pattern(
+        target: super::node::Node,
+        is_decleration: bool,
+        bound_names: &mut Vec<std::rc::Rc<str>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        match target {
+            super::node::Node::IdentifierExpression { name } => {
+                if name.as_ref() == "_" {
+                    return Some(super::node::Node::WildcardPattern);
+                }
+
+                Self::bind_name(&name, is_decleration, bound_names, error_handler)?;
+
+                Some(super::node::Node::IdentifierPattern { name })
+            }
+            super::node::Node::MemberExpression {
+                notation_type:
+                    super::node::MemberNotationType::Bracket | super::node::MemberNotationType::Dot,
+                ..
+            } if !is_decleration => Some(target),
+            super::node::Node::ArrayExpression { elements } => {
+                let element_count: usize = elements.len();
+                let mut patterns: Vec<Box<super::node::Node>> = Vec::new();
+
+                for (index, element) in elements.into_iter().enumerate() {
+                    let pattern: super::node::Node = match *element {
+                        super::node::Node::SpreadExpression { argument }
+                            if index + 1 == element_count =>
+                        {
+                            super::node::Node::RestPattern {
+                                argument: Box::new(Self::parse_destructuring_pattern(
+                                    *argument,
+                                    is_decleration,
+                                    bound_names,
+                                    error_handler,
+                                )?),
+                            }
+                        }
+                        element => Self::parse_destructuring_pattern(
+                            element,
+                            is_decleration,
+                            bound_names,
+                            error_handler,
+                        )?,
+                    };
+
+                    patterns.push(Box::new(pattern));
+                }
+
+                Some(super::node::Node::ArrayPattern { elements: patterns })
+            }
+            super::node::Node::TableExpression { properties } => {
+                let mut patterns: Vec<(Box<super::node::Node>, Box<super::node::Node>)> =
+                    Vec::new();
+
+                for (key, value, key_type, _) in properties {
+                    if key_type == super::node::PropertyKeyType::Computed {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(
+                            "invalid assignment target: computed table key".to_string(),
+                        ));
+
+                        return None;
+                    }
+
+                    let pattern: super::node::Node = Self::parse_destructuring_pattern(
+                        *value,
+                        is_decleration,
+                        bound_names,
+                        error_handler,
+                    )?;
+
+                    patterns.push((key, Box::new(pattern)));
+                }
+
+                Some(super::node::Node::TablePattern {
+                    properties: patterns,
                 })
             }
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(
+                    "invalid assignment target".to_string(),
+                ));
+
+                None
+            }
+        }
+    }
+
+    fn bind_name(
+        name: &std::rc::Rc<str>,
+        is_decleration: bool,
+        bound_names: &mut Vec<std::rc::Rc<str>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        if is_decleration && bound_names.contains(name) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "duplicate binding: {name}"
+            )));
+
+            return None;
+        }
+
+        bound_names.push(name.clone());
+
+        Some(())
+    }
+
+    fn parse_expression(
+        &mut self,
+        precedence: &super::node::Precedence,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let left_argument: super::node::Node = match self.peek_current_token() {
+            super::token::Token::Literal(..) => self.parse_literal_expression(error_handler),
+            super::token::Token::Identifier(..) => self.parse_identifier_expression(error_handler),
+            super::token::Token::String(..) => self.parse_string_expression(error_handler),
+            super::token::Token::StringHead(..) => {
+                self.parse_interpolated_string_expression(error_handler)
+            }
+            super::token::Token::SymbolLeftSquareBracket => {
+                self.parse_array_expression(error_handler)
+            }
+            super::token::Token::KeywordTable => self.parse_table_expression(error_handler),
+            super::token::Token::KeywordIf => self.parse_if_statement(error_handler),
+            super::token::Token::KeywordMatch => self.parse_match_statement(error_handler),
+            super::token::Token::KeywordLoop | super::token::Token::Label(..) => {
+                self.parse_loop_statement(error_handler)
+            }
+            super::token::Token::SymbolLeftBrace => self.parse_block_statement(error_handler),
+            super::token::Token::KeywordFunc => self.parse_func_expression(error_handler),
+            super::token::Token::SymbolPipe | super::token::Token::SymbolPipePipe => {
+                self.parse_lambda_expression(error_handler)
+            }
+            super::token::Token::SymbolLeftParenthesis => {
+                self.parse_grouped_expression(error_handler)
+            }
+            super::token::Token::SymbolMinus
+            | super::token::Token::SymbolBang => self.parse_prefix_expression(error_handler),
+            super::token::Token::SymbolDotDot | super::token::Token::SymbolDotDotEqual => {
+                self.parse_range_expression(None, error_handler)
+            }
+            super::token::Token::KeywordYield => self.parse_yield_expression(error_handler),
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "unexpected token: {}",
+                    self.peek_current_token().type_as_string(),
+                )));
+
+                return None;
+            }
+        }?;
+
+        self.parse_expression_from(left_argument, precedence, error_handler)
+    }
+
+    fn parse_expression_from(
+        &mut self,
+        mut left_argument: super::node::Node,
+        precedence: &super::node::Precedence,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        while precedence < &Self::precedence_of_token(self.peek_current_token()) {
+            match self.peek_current_token() {
+                super::token::Token::SymbolPlus
+                | super::token::Token::SymbolMinus
+                | super::token::Token::SymbolAsterisk
+                | super::token::Token::SymbolSlash
+                | super::token::Token::SymbolPipe
+                | super::token::Token::SymbolAmpersand
+                | super::token::Token::SymbolCaret
+                | super::token::Token::SymbolLessLess
+                | super::token::Token::SymbolGreaterGreater
+                | super::token::Token::SymbolLess
+                | super::token::Token::SymbolGreater
+                | super::token::Token::SymbolEqualEqual
+                | super::token::Token::SymbolLessEqual
+                | super::token::Token::SymbolGreaterEqual
+                | super::token::Token::SymbolBangEqual
+                | super::token::Token::SymbolPipePipe
+                | super::token::Token::SymbolAmpersandAmpersand
+                | super::token::Token::SymbolQuestionQuestion => {
+                    left_argument = self.parse_infix_expression(left_argument, error_handler)?;
+                }
+                super::token::Token::SymbolPipeGreater => {
+                    left_argument = self.parse_pipeline_expression(left_argument, error_handler)?;
+                }
+                super::token::Token::SymbolDotDot | super::token::Token::SymbolDotDotEqual => {
+                    left_argument =
+                        self.parse_range_expression(Some(left_argument), error_handler)?;
+                }
+                super::token::Token::SymbolLeftParenthesis => {
+                    left_argument = self.parse_call_expression(left_argument, error_handler)?;
+                }
+                super::token::Token::SymbolLeftSquareBracket if self.semicolon_skippable_now => {
+                    break;
+                }
+                super::token::Token::SymbolLeftSquareBracket
+                | super::token::Token::SymbolQuestionLeftSquareBracket
+                | super::token::Token::SymbolDot
+                | super::token::Token::SymbolQuestionDot => {
+                    left_argument = self.parse_member_expression(left_argument, error_handler)?;
+                }
+                super::token::Token::SymbolColon => {
+                    left_argument =
+                        self.parse_method_call_expression(left_argument, error_handler)?;
+                }
+                _ => break,
+            }
+        }
+
+        Some(left_argument)
+    }
+
+    fn parse_literal_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let literal_expression: super::node::Node = match self.peek_current_token() {
+            super::token::Token::Literal(value) => super::node::Node::LiteralExpression {
+                value: value.clone(),
+            },
+            _ => unreachable!(),
+        };
+
+        self.read_token(error_handler)?;
+
+        Some(literal_expression)
+    }
+
+    fn parse_identifier_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let identifier_expression: super::node::Node = match self.peek_current_token() {
+            super::token::Token::Identifier(name) => super::node::Node::IdentifierExpression {
+                name: name.clone(),
+            },
+            _ => unreachable!(),
+        };
+
+        self.read_token(error_handler)?;
+
+        Some(identifier_expression)
+    }
+
+    fn parse_string_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let string_expression: super::node::Node = match self.peek_current_token() {
+            super::token::Token::String(value) => super::node::Node::StringExpression {
+                value: value.clone(),
+            },
             _ => unreachable!(),
+        };
+
+        self.read_token(error_handler)?;
+
+        Some(string_expression)
+    }
+
+    fn parse_interpolated_string_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let mut parts: Vec<Box<super::node::Node>> = Vec::new();
+
+        loop {
+            let (value, is_tail): (std::rc::Rc<str>, bool) = match self.peek_current_token() {
+                super::token::Token::StringHead(value)
+                | super::token::Token::StringMiddle(value) => (value.clone(), false),
+                super::token::Token::StringTail(value) => (value.clone(), true),
+                _ => {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: StringMiddle or token: StringTail, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            };
+
+            if !value.is_empty() {
+                parts.push(Box::new(super::node::Node::StringExpression { value }));
+            }
+
+            self.read_token(error_handler)?;
+
+            if is_tail {
+                break;
+            }
+
+            let part: super::node::Node =
+                self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+            parts.push(Box::new(part));
         }
+
+        Some(super::node::Node::InterpolatedStringExpression { parts })
+    }
+
+    fn parse_array_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let mut elements: Vec<Box<super::node::Node>> = Vec::new();
+
+        while self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+            let element: super::node::Node =
+                if self.peek_current_token() == &super::token::Token::SymbolDotDotDot {
+                    self.parse_spread_expression(error_handler)?
+                } else {
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?
+                };
+
+            elements.push(Box::new(element));
+
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolComma, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(super::node::Node::ArrayExpression { elements })
+    }
+
+    fn parse_spread_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let argument: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+        Some(super::node::Node::SpreadExpression {
+            argument: Box::new(argument),
+        })
+    }
+
+    fn parse_table_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let mut properties: Vec<super::node::TableProperty> = Vec::new();
+        let mut constant_keys: Vec<String> = Vec::new();
+
+        if self.peek_current_token() == &super::token::Token::SymbolLeftSquareBracket {
+            self.read_token(error_handler)?;
+
+            while self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                let doc_comment: Option<std::rc::Rc<str>> = self.doc_comment_now.clone();
+
+                let (key, value, key_type): (
+                    super::node::Node,
+                    super::node::Node,
+                    super::node::PropertyKeyType,
+                ) = self.parse_table_property(error_handler)?;
+
+                let constant_key: Option<String> = match (&key, &key_type) {
+                    (
+                        super::node::Node::IdentifierExpression { name: value },
+                        super::node::PropertyKeyType::Static,
+                    )
+                    | (super::node::Node::StringExpression { value }, _) => {
+                        Some(format!("\"{value}\""))
+                    }
+                    (super::node::Node::LiteralExpression { value }, _) => Some(value.to_string()),
+                    _ => None,
+                };
+
+                if let Some(constant_key) = constant_key {
+                    if constant_keys.contains(&constant_key) {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                            "duplicate table key: {constant_key}"
+                        )));
+
+                        return None;
+                    }
+
+                    constant_keys.push(constant_key);
+                }
+
+                properties.push((Box::new(key), Box::new(value), key_type, doc_comment));
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if self.peek_current_token()
+                    != &super::token::Token::SymbolRightSquareBracket
+                {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+
+            self.read_token(error_handler)?;
+        }
+
+        Some(super::node::Node::TableExpression { properties })
+    }
+
+    fn parse_table_property(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<(
+        super::node::Node,
+        super::node::Node,
+        super::node::PropertyKeyType,
+    )> {
+        let (key, key_type): (super::node::Node, super::node::PropertyKeyType) = match self
+            .peek_current_token()
+        {
+            super::token::Token::String(..) => (
+                self.parse_string_expression(error_handler)?,
+                super::node::PropertyKeyType::Static,
+            ),
+            super::token::Token::Literal(..) => (
+                self.parse_literal_expression(error_handler)?,
+                super::node::PropertyKeyType::Static,
+            ),
+            super::token::Token::Identifier(..) => (
+                self.parse_identifier_expression(error_handler)?,
+                super::node::PropertyKeyType::Static,
+            ),
+            super::token::Token::SymbolLeftSquareBracket => {
+                self.read_token(error_handler)?;
+
+                let key: super::node::Node =
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolRightSquareBracket, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                self.read_token(error_handler)?;
+
+                (key, super::node::PropertyKeyType::Computed)
+            }
+            super::token::Token::KeywordFunc => {
+                self.read_token(error_handler)?;
+
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let key: super::node::Node = self.parse_identifier_expression(error_handler)?;
+
+                let value: super::node::Node =
+                    self.parse_func_parameters_and_body(None, error_handler)?;
+
+                return Some((key, value, super::node::PropertyKeyType::Static));
+            }
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: Literal, token: Identifier, token: String, token: SymbolLeftSquareBracket or token: KeywordFunc, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        };
+
+        if let super::node::Node::IdentifierExpression { name } = &key
+            && (self.peek_current_token() == &super::token::Token::SymbolComma
+                || self.peek_current_token() == &super::token::Token::SymbolRightSquareBracket)
+        {
+            let value: super::node::Node =
+                super::node::Node::IdentifierExpression { name: name.clone() };
+
+            return Some((key, value, key_type));
+        }
+
+        if self.peek_current_token() != &super::token::Token::SymbolColon {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolColon, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        self.read_token(error_handler)?;
+
+        let value: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+        Some((key, value, key_type))
+    }
+
+    fn parse_func_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let doc_comment: Option<std::rc::Rc<str>> = self.doc_comment_now.clone();
+
+        self.read_token(error_handler)?;
+
+        self.parse_func_parameters_and_body(doc_comment, error_handler)
+    }
+
+    fn parse_func_parameters_and_body(
+        &mut self,
+        doc_comment: Option<std::rc::Rc<str>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let mut parameters: Vec<Box<super::node::Node>> = Vec::new();
+        let mut default_arguments: Vec<Option<Box<super::node::Node>>> = Vec::new();
+        let mut variadic_parameter: Option<Box<super::node::Node>> = None;
+        let mut parameter_types: Vec<Option<Box<super::node::Node>>> = Vec::new();
+        let mut variadic_parameter_type: Option<Box<super::node::Node>> = None;
+
+        if self.peek_current_token() == &super::token::Token::SymbolLeftParenthesis {
+            self.read_token(error_handler)?;
+
+            while self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+                if self.peek_current_token() == &super::token::Token::SymbolDotDotDot {
+                    self.read_token(error_handler)?;
+
+                    if !matches!(
+                        self.peek_current_token(),
+                        super::token::Token::Identifier(..)
+                    ) {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                            "expected token: Identifier, but got token: {}",
+                            self.peek_current_token().type_as_string()
+                        )));
+
+                        return None;
+                    }
+
+                    let parameter: super::node::Node =
+                        self.parse_identifier_expression(error_handler)?;
+
+                    variadic_parameter = Some(Box::new(parameter));
+                    variadic_parameter_type = self.parse_type_annotation(error_handler)?;
+
+                    if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+                        error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                            "expected token: SymbolRightParenthesis, but got token: {}",
+                            self.peek_current_token().type_as_string()
+                        )));
+
+                        return None;
+                    }
+
+                    break;
+                }
+
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier or token: SymbolDotDotDot, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let parameter: super::node::Node =
+                    self.parse_identifier_expression(error_handler)?;
+
+                let parameter_type: Option<Box<super::node::Node>> =
+                    self.parse_type_annotation(error_handler)?;
+
+                let default_argument: Option<Box<super::node::Node>> =
+                    if self.peek_current_token() == &super::token::Token::SymbolEqual {
+                        self.read_token(error_handler)?;
+
+                        Some(Box::new(self.parse_expression(
+                            &super::node::Precedence::Lowest,
+                            error_handler,
+                        )?))
+                    } else {
+                        None
+                    };
+
+                if let super::node::Node::IdentifierExpression { name } = &parameter
+                    && default_argument.is_none()
+                    && default_arguments.last().is_some_and(Option::is_some)
+                {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "required parameter after default parameter: {name}"
+                    )));
+
+                    return None;
+                }
+
+                parameters.push(Box::new(parameter));
+                default_arguments.push(default_argument);
+                parameter_types.push(parameter_type);
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis
+                {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+
+            self.read_token(error_handler)?;
+        } else if matches!(
+            self.peek_current_token(),
+            super::token::Token::Identifier(..)
+        ) {
+            loop {
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let parameter: super::node::Node =
+                    self.parse_identifier_expression(error_handler)?;
+
+                let parameter_type: Option<Box<super::node::Node>> =
+                    self.parse_type_annotation(error_handler)?;
+
+                parameters.push(Box::new(parameter));
+                default_arguments.push(None);
+                parameter_types.push(parameter_type);
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if matches!(
+                    self.peek_current_token(),
+                    super::token::Token::SymbolLeftBrace | super::token::Token::SymbolMinusGreater
+                ) {
+                    break;
+                } else {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+        }
+
+        let return_type: Option<Box<super::node::Node>> =
+            if self.peek_current_token() == &super::token::Token::SymbolMinusGreater {
+                self.read_token(error_handler)?;
+
+                Some(Box::new(self.parse_type(error_handler)?))
+            } else {
+                None
+            };
+
+        if self.peek_current_token() != &super::token::Token::SymbolLeftBrace {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolLeftBrace, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        Self::check_duplicate_parameters(&parameters, &variadic_parameter, error_handler)?;
+
+        let generator_state: Option<bool> = self.generator_state.replace(false);
+
+        let body: super::node::Node = self.parse_block_statement(error_handler)?;
+
+        let is_generator: bool = self.generator_state == Some(true);
+
+        self.generator_state = generator_state;
+
+        Some(super::node::Node::FuncExpression {
+            parameters,
+            default_arguments,
+            variadic_parameter,
+            parameter_types,
+            variadic_parameter_type,
+            return_type,
+            body: Box::new(body),
+            is_generator,
+            doc_comment,
+        })
+    }
+
+    fn check_duplicate_parameters(
+        parameters: &[Box<super::node::Node>],
+        variadic_parameter: &Option<Box<super::node::Node>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let mut parameter_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+        for parameter in parameters.iter().chain(variadic_parameter.iter()) {
+            if let super::node::Node::IdentifierExpression { name } = parameter.as_ref() {
+                if parameter_names.contains(name) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "duplicate parameter: {name}"
+                    )));
+
+                    return None;
+                }
+
+                parameter_names.push(name.clone());
+            }
+        }
+
+        Some(())
+    }
+
+    fn parse_lambda_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let mut parameters: Vec<Box<super::node::Node>> = Vec::new();
+
+        if self.peek_current_token() == &super::token::Token::SymbolPipe {
+            self.read_token(error_handler)?;
+
+            while self.peek_current_token() != &super::token::Token::SymbolPipe {
+                if !matches!(
+                    self.peek_current_token(),
+                    super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let parameter: super::node::Node =
+                    self.parse_identifier_expression(error_handler)?;
+
+                parameters.push(Box::new(parameter));
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if self.peek_current_token() != &super::token::Token::SymbolPipe {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        Self::check_duplicate_parameters(&parameters, &None, error_handler)?;
+
+        let generator_state: Option<bool> = self.generator_state.replace(false);
+
+        let body: super::node::Node =
+            if self.peek_current_token() == &super::token::Token::SymbolLeftBrace {
+                self.parse_block_statement(error_handler)?
+            } else {
+                let line: usize = self.current_line;
+                let argument: super::node::Node =
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                super::node::Node::BlockStatement {
+                    body: vec![Box::new(super::node::Node::ReturnStatement {
+                        arguments: vec![Box::new(argument)],
+                        attributes: Vec::new(),
+                        line,
+                    })],
+                    value: None,
+                    attributes: Vec::new(),
+                    line,
+                }
+            };
+
+        let is_generator: bool = self.generator_state == Some(true);
+
+        self.generator_state = generator_state;
+
+        Some(super::node::Node::FuncExpression {
+            default_arguments: parameters.iter().map(|_| None).collect(),
+            parameter_types: parameters.iter().map(|_| None).collect(),
+            parameters,
+            variadic_parameter: None,
+            variadic_parameter_type: None,
+            return_type: None,
+            body: Box::new(body),
+            is_generator,
+            doc_comment: None,
+        })
+    }
+
+    fn parse_grouped_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let expression: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+        if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolRightParenthesis, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(expression)
+    }
+
+    fn parse_prefix_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let operator: super::token::Token = self.peek_current_token().clone();
+
+        self.read_token(error_handler)?;
+
+        let argument: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Prefix, error_handler)?;
+
+        Some(super::node::Node::PrefixExpression {
+            argument: Box::new(argument),
+            operator,
+        })
+    }
+
+    fn parse_pipeline_expression(
+        &mut self,
+        argument: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let target: super::node::Node =
+            self.parse_expression(&super::node::Precedence::Pipeline, error_handler)?;
+
+        Some(super::node::Node::PipelineExpression {
+            argument: Box::new(argument),
+            target: Box::new(target),
+        })
+    }
+
+    fn is_expression_end(&self) -> bool {
+        self.semicolon_skippable_now
+            || matches!(
+                self.peek_current_token(),
+                super::token::Token::SymbolRightSquareBracket
+                    | super::token::Token::SymbolRightParenthesis
+                    | super::token::Token::SymbolComma
+                    | super::token::Token::SymbolSemicolon
+            )
+    }
+
+    fn parse_range_expression(
+        &mut self,
+        start: Option<super::node::Node>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        if matches!(start, Some(super::node::Node::RangeExpression { .. })) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "unexpected token: {}, ranges cannot be chained",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let is_inclusive: bool =
+            self.peek_current_token() == &super::token::Token::SymbolDotDotEqual;
+
+        self.read_token(error_handler)?;
+
+        if matches!(
+            self.peek_current_token(),
+            super::token::Token::SymbolDotDot | super::token::Token::SymbolDotDotEqual
+        ) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "unexpected token: {}, ranges cannot be chained",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let has_end: bool = !self.is_expression_end();
+
+        if is_inclusive && !has_end {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected end of inclusive range, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let end: Option<Box<super::node::Node>> = if has_end {
+            Some(Box::new(self.parse_expression(
+                &super::node::Precedence::Range,
+                error_handler,
+            )?))
+        } else {
+            None
+        };
+
+        Some(super::node::Node::RangeExpression {
+            start: start.map(Box::new),
+            end,
+            is_inclusive,
+        })
+    }
+
+    fn parse_yield_expression(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        if self.generator_state.is_none() {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "unexpected token: {}, only allowed inside a func body",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        self.read_token(error_handler)?;
+
+        self.generator_state = Some(true);
+
+        let argument: Option<Box<super::node::Node>> = if self.is_expression_end() {
+            None
+        } else {
+            Some(Box::new(self.parse_expression(
+                &super::node::Precedence::Assignment,
+                error_handler,
+            )?))
+        };
+
+        Some(super::node::Node::YieldExpression { argument })
+    }
+
+    fn parse_infix_expression(
+        &mut self,
+        left_argument: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let operator: super::token::Token = self.peek_current_token().clone();
+
+        self.read_token(error_handler)?;
+
+        let precedence: super::node::Precedence = Self::precedence_of_token(&operator);
+
+        let right_argument: super::node::Node =
+            self.parse_expression(&precedence, error_handler)?;
+
+        Some(super::node::Node::InfixExpression {
+            left_argument: Box::new(left_argument),
+            right_argument: Box::new(right_argument),
+            operator,
+        })
+    }
+
+    fn parse_call_expression(
+        &mut self,
+        target: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let arguments: Vec<Box<super::node::Node>> = self.parse_call_arguments(error_handler)?;
+
+        Some(super::node::Node::CallExpression {
+            target: Box::new(target),
+            arguments,
+            is_optional: false,
+        })
+    }
+
+    fn parse_method_call_expression(
+        &mut self,
+        target: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        self.parse_method_call(target, error_handler)
+    }
+
+    fn parse_method_call(
+        &mut self,
+        target: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        if !matches!(
+            self.peek_current_token(),
+            super::token::Token::Identifier(..)
+        ) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: Identifier, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let method: super::node::Node = self.parse_identifier_expression(error_handler)?;
+
+        if self.peek_current_token() != &super::token::Token::SymbolLeftParenthesis {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolLeftParenthesis, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        let arguments: Vec<Box<super::node::Node>> = self.parse_call_arguments(error_handler)?;
+
+        Some(super::node::Node::MethodCallExpression {
+            target: Box::new(target),
+            method: Box::new(method),
+            arguments,
+        })
+    }
+
+    #[allow(clippy::vec_box)]
+    fn parse_call_arguments(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Vec<Box<super::node::Node>>> {
+        self.read_token(error_handler)?;
+
+        let mut arguments: Vec<Box<super::node::Node>> = Vec::new();
+
+        if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+            loop {
+                let argument: super::node::Node =
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                arguments.push(Box::new(argument));
+
+                if self.peek_current_token() == &super::token::Token::SymbolComma {
+                    self.read_token(error_handler)?;
+                } else if self.peek_current_token() == &super::token::Token::SymbolRightParenthesis
+                {
+                    break;
+                } else {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolComma, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(arguments)
+    }
+
+    fn parse_member_expression(
+        &mut self,
+        target: super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        match self.peek_current_token() {
+            super::token::Token::SymbolLeftSquareBracket
+            | super::token::Token::SymbolQuestionLeftSquareBracket => {
+                let notation_type: super::node::MemberNotationType =
+                    if self.peek_current_token() == &super::token::Token::SymbolLeftSquareBracket {
+                        super::node::MemberNotationType::Bracket
+                    } else {
+                        super::node::MemberNotationType::OptionalBracket
+                    };
+
+                self.read_token(error_handler)?;
+
+                let argument: super::node::Node =
+                    self.parse_expression(&super::node::Precedence::Lowest, error_handler)?;
+
+                if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolRightSquareBracket, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                self.read_token(error_handler)?;
+
+                Some(super::node::Node::MemberExpression {
+                    target: Box::new(target),
+                    argument: Box::new(argument),
+                    notation_type,
+                })
+            }
+            super::token::Token::SymbolDot | super::token::Token::SymbolQuestionDot => {
+                let notation_type: super::node::MemberNotationType =
+                    if self.peek_current_token() == &super::token::Token::SymbolDot {
+                        super::node::MemberNotationType::Dot
+                    } else {
+                        super::node::MemberNotationType::OptionalDot
+                    };
+
+                self.read_token(error_handler)?;
+
+                if notation_type == super::node::MemberNotationType::OptionalDot
+                    && self.peek_current_token() == &super::token::Token::SymbolLeftParenthesis
+                {
+                    let arguments: Vec<Box<super::node::Node>> =
+                        self.parse_call_arguments(error_handler)?;
+
+                    return Some(super::node::Node::CallExpression {
+                        target: Box::new(target),
+                        arguments,
+                        is_optional: true,
+                    });
+                }
+
+                if !matches!(
+                    self.peek_current_token(),
+                    &super::token::Token::Identifier(..)
+                ) {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                let argument: super::node::Node =
+                    self.parse_identifier_expression(error_handler)?;
+
+                Some(super::node::Node::MemberExpression {
+                    target: Box::new(target),
+                    argument: Box::new(argument),
+                    notation_type,
+                })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_type_annotation(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Option<Box<super::node::Node>>> {
+        if self.peek_current_token() != &super::token::Token::SymbolColon {
+            return Some(None);
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(Some(Box::new(self.parse_type(error_handler)?)))
+    }
+
+    fn parse_type(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let mut types: Vec<Box<super::node::Node>> =
+            vec![Box::new(self.parse_optional_type(error_handler)?)];
+
+        while self.peek_current_token() == &super::token::Token::SymbolPipe {
+            self.read_token(error_handler)?;
+
+            types.push(Box::new(self.parse_optional_type(error_handler)?));
+        }
+
+        if types.len() == 1 {
+            return types.pop().map(|argument| *argument);
+        }
+
+        Some(super::node::Node::UnionType { types })
+    }
+
+    fn parse_optional_type(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let argument: super::node::Node = match self.peek_current_token() {
+            super::token::Token::Identifier(..) | super::token::Token::Literal(..) => {
+                self.parse_primitive_type(error_handler)?
+            }
+            super::token::Token::KeywordTable => self.parse_table_type(error_handler)?,
+            super::token::Token::KeywordFunc => self.parse_func_type(error_handler)?,
+            super::token::Token::SymbolLeftParenthesis => {
+                self.read_token(error_handler)?;
+
+                let argument: super::node::Node = self.parse_type(error_handler)?;
+
+                if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: SymbolRightParenthesis, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                self.read_token(error_handler)?;
+
+                argument
+            }
+            _ => {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected type, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        };
+
+        if self.peek_current_token() == &super::token::Token::SymbolQuestion {
+            self.read_token(error_handler)?;
+
+            return Some(super::node::Node::OptionalType {
+                argument: Box::new(argument),
+            });
+        }
+
+        Some(argument)
+    }
+
+    fn parse_primitive_type(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        let name: std::rc::Rc<str> = match self.peek_current_token() {
+            super::token::Token::Identifier(name) | super::token::Token::Literal(name) => {
+                name.clone()
+            }
+            _ => unreachable!(),
+        };
+
+        if !matches!(
+            name.as_ref(),
+            "int" | "float" | "string" | "bool" | "none" | "any"
+        ) {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "unknown type: {name}"
+            )));
+
+            return None;
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(super::node::Node::PrimitiveType { name })
+    }
+
+    fn parse_table_type(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        let mut properties: Vec<(std::rc::Rc<str>, Box<super::node::Node>)> = Vec::new();
+
+        if self.peek_current_token() != &super::token::Token::SymbolLeftSquareBracket
+            || self.semicolon_skippable_now
+        {
+            return Some(super::node::Node::TableType { properties });
+        }
+
+        self.read_token(error_handler)?;
+
+        while self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+            let key: std::rc::Rc<str> = match self.peek_current_token() {
+                super::token::Token::Identifier(name) => name.clone(),
+                _ => {
+                    error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                        "expected token: Identifier, but got token: {}",
+                        self.peek_current_token().type_as_string()
+                    )));
+
+                    return None;
+                }
+            };
+
+            if properties.iter().any(|(name, _)| name == &key) {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "duplicate table key: {key}"
+                )));
+
+                return None;
+            }
+
+            self.read_token(error_handler)?;
+
+            if self.peek_current_token() != &super::token::Token::SymbolColon {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolColon, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+
+            self.read_token(error_handler)?;
+
+            let value: super::node::Node = self.parse_type(error_handler)?;
+
+            properties.push((key, Box::new(value)));
+
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else if self.peek_current_token() != &super::token::Token::SymbolRightSquareBracket {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolComma, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        Some(super::node::Node::TableType { properties })
+    }
+
+    fn parse_func_type(
+        &mut self,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<super::node::Node> {
+        self.read_token(error_handler)?;
+
+        if self.peek_current_token() != &super::token::Token::SymbolLeftParenthesis {
+            error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                "expected token: SymbolLeftParenthesis, but got token: {}",
+                self.peek_current_token().type_as_string()
+            )));
+
+            return None;
+        }
+
+        self.read_token(error_handler)?;
+
+        let mut parameter_types: Vec<Box<super::node::Node>> = Vec::new();
+
+        while self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+            let parameter_type: super::node::Node = self.parse_type(error_handler)?;
+
+            parameter_types.push(Box::new(parameter_type));
+
+            if self.peek_current_token() == &super::token::Token::SymbolComma {
+                self.read_token(error_handler)?;
+            } else if self.peek_current_token() != &super::token::Token::SymbolRightParenthesis {
+                error_handler.set_error(super::error_handler::Error::SyntaxError(format!(
+                    "expected token: SymbolComma, but got token: {}",
+                    self.peek_current_token().type_as_string()
+                )));
+
+                return None;
+            }
+        }
+
+        self.read_token(error_handler)?;
+
+        let return_type: Option<Box<super::node::Node>> =
+            if self.peek_current_token() == &super::token::Token::SymbolMinusGreater {
+                self.read_token(error_handler)?;
+
+                Some(Box::new(self.parse_optional_type(error_handler)?))
+            } else {
+                None
+            };
+
+        Some(super::node::Node::FuncType {
+            parameter_types,
+            return_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::{Error, ErrorHandler, Warning};
+    use super::super::node::Node;
+
+    fn parse(input: &str) -> (Option<Node>, ErrorHandler) {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let mut lexer: super::super::lexer::Lexer<'_> = super::super::lexer::Lexer::new(input);
+        let mut parser: super::Parser<'_> = super::Parser::new(&mut lexer);
+
+        let program: Option<Node> = parser.parse(&mut error_handler);
+
+        (program, error_handler)
+    }
+
+    fn parse_body(input: &str) -> Vec<Node> {
+        let (program, error_handler) = parse(input);
+
+        assert_eq!(error_handler.error(), None, "{input}");
+
+        match program {
+            Some(Node::ProgramStatement { body }) => {
+                body.into_iter().map(|statement| *statement).collect()
+            }
+            program => panic!("expected a program, but got {program:?}"),
+        }
+    }
+
+    fn parse_argument(input: &str) -> Node {
+        let body: Vec<Node> = parse_body(input);
+
+        match body.into_iter().next() {
+            Some(Node::AssignmentStatement { mut arguments, .. }) => *arguments.remove(0),
+            Some(Node::ExpressionStatement { argument, .. }) => *argument,
+            statement => panic!("expected an assignment or expression, but got {statement:?}"),
+        }
+    }
+
+    fn parse_func_body(input: &str) -> Vec<Node> {
+        match parse_argument(input) {
+            Node::FuncExpression { body, .. } => match *body {
+                Node::BlockStatement { body, .. } => {
+                    body.into_iter().map(|statement| *statement).collect()
+                }
+                body => panic!("expected a block, but got {body:?}"),
+            },
+            argument => panic!("expected a func, but got {argument:?}"),
+        }
+    }
+
+    fn parse_type_annotation(input: &str) -> Node {
+        match parse_body(input).into_iter().next() {
+            Some(Node::AssignmentStatement {
+                mut type_annotations,
+                ..
+            }) => match type_annotations.remove(0) {
+                Some(type_annotation) => *type_annotation,
+                None => panic!("expected a type annotation in {input}"),
+            },
+            statement => panic!("expected an assignment, but got {statement:?}"),
+        }
+    }
+
+    fn assert_warnings(input: &str, messages: &[&str]) {
+        let (program, error_handler) = parse(input);
+
+        assert!(program.is_some(), "{input}");
+        assert_eq!(
+            error_handler.warnings(),
+            messages
+                .iter()
+                .map(|message| Warning::LintWarning(message.to_string()))
+                .collect::<Vec<Warning>>(),
+            "{input}"
+        );
+    }
+
+    fn assert_syntax_error(input: &str, message: &str) {
+        let (program, error_handler) = parse(input);
+
+        assert!(program.is_none(), "{input}");
+        assert_eq!(
+            error_handler.error(),
+            Some(&Error::SyntaxError(message.to_string())),
+            "{input}"
+        );
+    }
+
+    #[test]
+    fn array_literal_with_spread_and_trailing_comma() {
+        let argument: Node = parse_argument("x := [1, ...rest,]");
+
+        let Node::ArrayExpression { elements } = argument else {
+            panic!("expected an array, but got {argument:?}");
+        };
+
+        assert_eq!(elements.len(), 2);
+        assert!(matches!(*elements[0], Node::LiteralExpression { .. }));
+        assert!(matches!(*elements[1], Node::SpreadExpression { .. }));
+    }
+
+    #[test]
+    fn empty_array_literal() {
+        assert!(matches!(
+            parse_argument("x := []"),
+            Node::ArrayExpression { elements } if elements.is_empty()
+        ));
+    }
+
+    #[test]
+    fn array_literal_rejects_empty_element() {
+        assert_syntax_error("x := [1,, 2]", "unexpected token: SymbolComma");
+    }
+
+    #[test]
+    fn table_literal_with_computed_shorthand_and_method_properties() {
+        let argument: Node = parse_argument("x := table [[k]: 1, a, func m self { },]");
+
+        let Node::TableExpression { properties } = argument else {
+            panic!("expected a table, but got {argument:?}");
+        };
+
+        assert_eq!(properties.len(), 3);
+        assert!(matches!(
+            (&*properties[0].0, &properties[0].2),
+            (
+                Node::IdentifierExpression { name },
+                super::super::node::PropertyKeyType::Computed
+            ) if name.as_ref() == "k"
+        ));
+        assert!(matches!(
+            (&*properties[1].1, &properties[1].2),
+            (
+                Node::IdentifierExpression { name },
+                super::super::node::PropertyKeyType::Static
+            ) if name.as_ref() == "a"
+        ));
+        assert!(matches!(*properties[2].1, Node::FuncExpression { .. }));
+    }
+
+    #[test]
+    fn table_literal_rejects_duplicate_keys() {
+        assert_syntax_error("x := table [a: 1, a: 2]", "duplicate table key: \"a\"");
+        assert_syntax_error("x := table [a: 1, \"a\": 2]", "duplicate table key: \"a\"");
+        assert_syntax_error(
+            "x := table [a: 1, func a { }]",
+            "duplicate table key: \"a\"",
+        );
+        assert_syntax_error("x := table [1: 1, 1: 2]", "duplicate table key: 1");
+    }
+
+    #[test]
+    fn table_literal_allows_repeated_computed_keys() {
+        parse_body("x := table [[k]: 1, [k]: 2]");
+    }
+
+    #[test]
+    fn func_parameters_with_defaults_and_variadic() {
+        let argument: Node = parse_argument("f := func (a, b = 2, ...rest) { }");
+
+        let Node::FuncExpression {
+            parameters,
+            default_arguments,
+            variadic_parameter,
+            ..
+        } = argument
+        else {
+            panic!("expected a func, but got {argument:?}");
+        };
+
+        assert_eq!(parameters.len(), 2);
+        assert!(default_arguments[0].is_none());
+        assert!(default_arguments[1].is_some());
+        assert!(matches!(
+            variadic_parameter.as_deref(),
+            Some(Node::IdentifierExpression { name }) if name.as_ref() == "rest"
+        ));
+    }
+
+    #[test]
+    fn func_without_parameters() {
+        assert!(matches!(
+            parse_argument("f := func () { }"),
+            Node::FuncExpression { parameters, .. } if parameters.is_empty()
+        ));
+    }
+
+    #[test]
+    fn func_rejects_duplicate_parameters() {
+        assert_syntax_error("f := func (a, a) { }", "duplicate parameter: a");
+        assert_syntax_error("f := func (a, ...a) { }", "duplicate parameter: a");
+        assert_syntax_error("f := func a, a { }", "duplicate parameter: a");
+    }
+
+    #[test]
+    fn func_rejects_required_parameter_after_default() {
+        assert_syntax_error(
+            "f := func (a = 1, b) { }",
+            "required parameter after default parameter: b",
+        );
+    }
+
+    #[test]
+    fn func_rejects_parameter_after_variadic() {
+        assert_syntax_error(
+            "f := func (...a, b) { }",
+            "expected token: SymbolRightParenthesis, but got token: SymbolComma",
+        );
+    }
+
+    #[test]
+    fn lambda_with_expression_body_returns_it() {
+        let argument: Node = parse_argument("f := |x| x * 2");
+
+        let Node::FuncExpression {
+            parameters, body, ..
+        } = argument
+        else {
+            panic!("expected a func, but got {argument:?}");
+        };
+
+        assert_eq!(parameters.len(), 1);
+        assert!(matches!(
+            *body,
+            Node::BlockStatement { body, .. }
+                if matches!(
+                    body.as_slice(),
+                    [statement] if matches!(
+                        statement.as_ref(),
+                        Node::ReturnStatement { arguments, .. } if arguments.len() == 1
+                    )
+                )
+        ));
+    }
+
+    #[test]
+    fn lambda_with_block_body() {
+        assert!(matches!(
+            parse_argument("f := || { return 1 }"),
+            Node::FuncExpression { parameters, .. } if parameters.is_empty()
+        ));
+    }
+
+    #[test]
+    fn lambda_rejects_duplicate_parameters() {
+        assert_syntax_error("f := |x, x| x", "duplicate parameter: x");
+    }
+
+    #[test]
+    fn if_expression_branches_have_values() {
+        let argument: Node = parse_argument("x := if c { 1 } else { 2 }");
+
+        let Node::IfStatement {
+            consequent_body,
+            alternate_body,
+            ..
+        } = argument
+        else {
+            panic!("expected an if, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            *consequent_body,
+            Node::BlockStatement {
+                value: Some(..),
+                ..
+            }
+        ));
+        assert!(matches!(
+            *alternate_body,
+            Node::BlockStatement {
+                value: Some(..),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn block_value_is_last_expression_without_semicolon() {
+        assert!(matches!(
+            parse_argument("x := { a := 1; a + 1 }"),
+            Node::BlockStatement { body, value: Some(value), .. }
+                if body.len() == 1 && matches!(*value, Node::InfixExpression { .. })
+        ));
+        assert!(matches!(
+            parse_argument("x := { 1; }"),
+            Node::BlockStatement { body, value: None, .. } if body.len() == 1
+        ));
+    }
+
+    #[test]
+    fn trailing_if_without_values_stays_in_block_body() {
+        let argument: Node = parse_argument("f := func { if c { return 1 } else { return 2 } }");
+
+        let Node::FuncExpression { body, .. } = argument else {
+            panic!("expected a func, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            *body,
+            Node::BlockStatement { body, value: None, .. } if body.len() == 1
+        ));
+    }
+
+    #[test]
+    fn match_arm_patterns() {
+        let body: Vec<Node> = parse_body(
+            "match x {
+                -1 => 1,
+                \"s\" => 2,
+                table [a, b: [c, d]] => 3,
+                [e, ...f] => 4,
+                y if y > 1 => 5,
+                _ => 6,
+            }",
+        );
+
+        let [Node::MatchStatement { arms, .. }] = body.as_slice() else {
+            panic!("expected a match, but got {body:?}");
+        };
+
+        let patterns: Vec<&Node> = arms
+            .iter()
+            .filter_map(|arm| match arm.as_ref() {
+                Node::MatchArm { pattern, .. } => Some(pattern.as_ref()),
+                _ => None,
+            })
+            .collect();
+
+        assert!(matches!(patterns[0], Node::LiteralPattern { value } if value.as_ref() == "-1"));
+        assert!(matches!(patterns[1], Node::StringPattern { value } if value.as_ref() == "s"));
+        assert!(matches!(patterns[2], Node::TablePattern { properties } if properties.len() == 2));
+        assert!(matches!(
+            patterns[3],
+            Node::ArrayPattern { elements }
+                if matches!(elements.last().map(AsRef::as_ref), Some(Node::RestPattern { .. }))
+        ));
+        assert!(matches!(patterns[4], Node::IdentifierPattern { .. }));
+        assert!(matches!(patterns[5], Node::WildcardPattern));
+        assert!(matches!(
+            arms[4].as_ref(),
+            Node::MatchArm {
+                guard: Some(..),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn match_rejects_invalid_patterns() {
+        assert_syntax_error(
+            "match x { -a => 1 }",
+            "expected token: Literal, but got token: Identifier",
+        );
+        assert_syntax_error("match x { [a, a] => 1 }", "duplicate binding: a");
+    }
+
+    #[test]
+    fn match_warns_about_unreachable_arms() {
+        assert_warnings(
+            "match x { _ => 1, 2 => 3 }",
+            &["unreachable match arm after catch-all pattern"],
+        );
+        assert_warnings(
+            "match x { 1 => 1, 1 => 2 }",
+            &["unreachable match arm: 1 is already matched"],
+        );
+        assert_warnings(
+            "match x { true => 1, false => 2, none => 3, 4 => 4 }",
+            &["unreachable match arm: true, false and none are already matched"],
+        );
+    }
+
+    #[test]
+    fn match_warns_about_missing_boolean_patterns() {
+        assert_warnings(
+            "match x { true => 1 }",
+            &["non-exhaustive match: missing pattern: false"],
+        );
+        assert_warnings("match x { true => 1, false => 2 }", &[]);
+        assert_warnings("match x { true => 1, _ => 2 }", &[]);
+    }
+
+    #[test]
+    fn destructuring_declarations() {
+        let body: Vec<Node> = parse_body(
+            "[x, ...rest] := xs
+            table [name, age: a] := person
+            _, err := f()",
+        );
+
+        let targets: Vec<&Node> = body
+            .iter()
+            .filter_map(|statement| match statement {
+                Node::AssignmentStatement { targets, .. } => Some(targets[0].as_ref()),
+                _ => None,
+            })
+            .collect();
+
+        assert!(matches!(targets[0], Node::ArrayPattern { elements } if elements.len() == 2));
+        assert!(matches!(targets[1], Node::TablePattern { properties } if properties.len() == 2));
+        assert!(matches!(targets[2], Node::WildcardPattern));
+    }
+
+    #[test]
+    fn multiple_assignment() {
+        assert!(matches!(
+            parse_body("a, b = b, a").as_slice(),
+            [Node::AssignmentStatement { targets, arguments, is_decleration: false, .. }]
+                if targets.len() == 2 && arguments.len() == 2
+        ));
+        parse_body("a.b, c[0] = 1, 2");
+    }
+
+    #[test]
+    fn destructuring_rejects_invalid_targets() {
+        assert_syntax_error("a, a := 1, 2", "duplicate binding: a");
+        assert_syntax_error("[...r, x] := xs", "invalid assignment target");
+        assert_syntax_error(
+            "table [[k]: v] := p",
+            "invalid assignment target: computed table key",
+        );
+        assert_syntax_error("f() = 1", "invalid assignment target");
+        assert_syntax_error("a.b := 1", "invalid assignment target");
+    }
+
+    #[test]
+    fn return_without_values() {
+        for input in [
+            "f := func { return }",
+            "f := func { return; }",
+            "f := func { return\nx }",
+        ] {
+            assert!(
+                matches!(
+                    parse_func_body(input).first(),
+                    Some(Node::ReturnStatement { arguments, .. }) if arguments.is_empty()
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn return_with_multiple_values() {
+        assert!(matches!(
+            parse_func_body("f := func { return a, b }").as_slice(),
+            [Node::ReturnStatement { arguments, .. }] if arguments.len() == 2
+        ));
+    }
+
+    #[test]
+    fn labeled_loop_with_break_value() {
+        let argument: Node = parse_argument("x := 'outer: loop { break 'outer 1 }");
+
+        let Node::LoopStatement { label, body, .. } = argument else {
+            panic!("expected a loop, but got {argument:?}");
+        };
+
+        assert_eq!(label.as_deref(), Some("outer"));
+        assert!(matches!(
+            *body,
+            Node::BlockStatement { body, .. }
+                if matches!(
+                    body.first().map(AsRef::as_ref),
+                    Some(Node::BreakStatement { label: Some(..), argument: Some(..), .. })
+                )
+        ));
+    }
+
+    #[test]
+    fn label_must_precede_loop() {
+        assert_syntax_error(
+            "'a: { }",
+            "expected token: KeywordLoop, but got token: SymbolLeftBrace",
+        );
+    }
+
+    #[test]
+    fn method_call() {
+        let argument: Node = parse_argument("obj:greet(\"hi\")");
+
+        let Node::MethodCallExpression {
+            target,
+            method,
+            arguments,
+        } = argument
+        else {
+            panic!("expected a method call, but got {argument:?}");
+        };
+
+        assert!(matches!(*target, Node::IdentifierExpression { .. }));
+        assert!(matches!(*method, Node::IdentifierExpression { name } if name.as_ref() == "greet"));
+        assert_eq!(arguments.len(), 1);
+        assert!(matches!(
+            parse_argument("x := a.b:c(1)"),
+            Node::MethodCallExpression { target, .. }
+                if matches!(*target, Node::MemberExpression { .. })
+        ));
+    }
+
+    #[test]
+    fn method_call_requires_name_and_arguments() {
+        assert_syntax_error(
+            "x := obj:greet",
+            "expected token: SymbolLeftParenthesis, but got token: Eof",
+        );
+        assert_syntax_error(
+            "x := obj:1()",
+            "expected token: Identifier, but got token: Literal",
+        );
+    }
+
+    #[test]
+    fn method_property_declares_self() {
+        let argument: Node = parse_argument("x := table [func greet self, msg { }]");
+
+        let Node::TableExpression { properties } = argument else {
+            panic!("expected a table, but got {argument:?}");
+        };
+
+        let Node::FuncExpression { parameters, .. } = properties[0].1.as_ref() else {
+            panic!("expected a func, but got {:?}", properties[0].1);
+        };
+
+        assert!(matches!(
+            parameters[0].as_ref(),
+            Node::IdentifierExpression { name } if name.as_ref() == "self"
+        ));
+    }
+
+    #[test]
+    fn optional_member_access_and_call() {
+        let argument: Node = parse_argument("x := a?.b?[k]");
+
+        let Node::MemberExpression {
+            target,
+            notation_type,
+            ..
+        } = argument
+        else {
+            panic!("expected a member expression, but got {argument:?}");
+        };
+
+        assert_eq!(
+            notation_type,
+            super::super::node::MemberNotationType::OptionalBracket
+        );
+        assert!(matches!(
+            *target,
+            Node::MemberExpression {
+                notation_type: super::super::node::MemberNotationType::OptionalDot,
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_argument("x := f?.(1)"),
+            Node::CallExpression {
+                is_optional: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn null_coalescing_is_left_associative_and_looser_than_logical() {
+        assert!(matches!(
+            parse_argument("x := a ?? b ?? c"),
+            Node::InfixExpression {
+                left_argument,
+                operator: super::super::token::Token::SymbolQuestionQuestion,
+                ..
+            } if matches!(*left_argument, Node::InfixExpression { .. })
+        ));
+        assert!(matches!(
+            parse_argument("x := a ?? b || c"),
+            Node::InfixExpression {
+                right_argument,
+                operator: super::super::token::Token::SymbolQuestionQuestion,
+                ..
+            } if matches!(*right_argument, Node::InfixExpression { .. })
+        ));
+    }
+
+    #[test]
+    fn interpolated_string_parts() {
+        let argument: Node = parse_argument("x := \"a {b} c {table [k: 1].k}\"");
+
+        let Node::InterpolatedStringExpression { parts } = argument else {
+            panic!("expected an interpolated string, but got {argument:?}");
+        };
+
+        assert_eq!(parts.len(), 4);
+        assert!(
+            matches!(parts[0].as_ref(), Node::StringExpression { value } if value.as_ref() == "a ")
+        );
+        assert!(matches!(
+            parts[1].as_ref(),
+            Node::IdentifierExpression { .. }
+        ));
+        assert!(
+            matches!(parts[2].as_ref(), Node::StringExpression { value } if value.as_ref() == " c ")
+        );
+        assert!(matches!(parts[3].as_ref(), Node::MemberExpression { .. }));
+    }
+
+    #[test]
+    fn nested_interpolated_strings() {
+        let argument: Node = parse_argument("x := \"{\"a{1}\"}\"");
+
+        let Node::InterpolatedStringExpression { parts } = argument else {
+            panic!("expected an interpolated string, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            parts.as_slice(),
+            [part] if matches!(part.as_ref(), Node::InterpolatedStringExpression { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_interpolations() {
+        assert_syntax_error("x := \"a {}\"", "unexpected token: StringTail");
+        assert_syntax_error(
+            "x := \"a {b",
+            "expected token: StringMiddle or token: StringTail, but got token: Eof",
+        );
+    }
+
+    #[test]
+    fn range_bounds() {
+        for (input, has_start, has_end, inclusive) in [
+            ("x := 1..3", true, true, false),
+            ("x := 1..=3", true, true, true),
+            ("x := ..5", false, true, false),
+            ("x := 2..", true, false, false),
+        ] {
+            let argument: Node = parse_argument(input);
+
+            let Node::RangeExpression {
+                start,
+                end,
+                is_inclusive,
+            } = argument
+            else {
+                panic!("expected a range, but got {argument:?}");
+            };
+
+            assert_eq!(start.is_some(), has_start, "{input}");
+            assert_eq!(end.is_some(), has_end, "{input}");
+            assert_eq!(is_inclusive, inclusive, "{input}");
+        }
+    }
+
+    #[test]
+    fn range_binds_looser_than_addition() {
+        let argument: Node = parse_argument("x := a + 1..b");
+
+        let Node::RangeExpression {
+            start: Some(start), ..
+        } = argument
+        else {
+            panic!("expected a range, but got {argument:?}");
+        };
+
+        assert!(matches!(*start, Node::InfixExpression { .. }));
+    }
+
+    #[test]
+    fn slicing() {
+        let argument: Node = parse_argument("x := xs[2..]");
+
+        let Node::MemberExpression { argument, .. } = argument else {
+            panic!("expected a member expression, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            *argument,
+            Node::RangeExpression {
+                end: None,
+                is_inclusive: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn chained_ranges() {
+        assert_syntax_error(
+            "x := 1..2..3",
+            "unexpected token: SymbolDotDot, ranges cannot be chained",
+        );
+        assert_syntax_error(
+            "x := 1..=2..=3",
+            "unexpected token: SymbolDotDotEqual, ranges cannot be chained",
+        );
+        assert_syntax_error(
+            "x := 1.. ..3",
+            "unexpected token: SymbolDotDot, ranges cannot be chained",
+        );
+    }
+
+    #[test]
+    fn inclusive_range_without_end() {
+        assert_syntax_error(
+            "x := 1..=",
+            "expected end of inclusive range, but got token: Eof",
+        );
+    }
+
+    #[test]
+    fn pipelines_are_left_associative() {
+        let argument: Node = parse_argument("x := a + 1 |> f |> g(2)");
+
+        let Node::PipelineExpression { argument, target } = argument else {
+            panic!("expected a pipeline, but got {argument:?}");
+        };
+
+        assert!(matches!(*target, Node::CallExpression { .. }));
+
+        let Node::PipelineExpression { argument, target } = *argument else {
+            panic!("expected a pipeline, but got {argument:?}");
+        };
+
+        assert!(matches!(*argument, Node::InfixExpression { .. }));
+        assert!(matches!(*target, Node::IdentifierExpression { .. }));
+    }
+
+    #[test]
+    fn pipeline_binds_looser_than_null_coalescing() {
+        let argument: Node = parse_argument("x := a ?? b |> f");
+
+        let Node::PipelineExpression { argument, .. } = argument else {
+            panic!("expected a pipeline, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            *argument,
+            Node::InfixExpression {
+                operator: super::super::token::Token::SymbolQuestionQuestion,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn pipeline_without_target() {
+        assert_syntax_error("x := a |>", "unexpected token: Eof");
+    }
+
+    #[test]
+    fn const_declarations() {
+        assert!(matches!(
+            parse_body("const a, b = 1, 2").as_slice(),
+            [Node::AssignmentStatement {
+                targets,
+                is_decleration: true,
+                is_constant: true,
+                ..
+            }] if targets.len() == 2
+        ));
+    }
+
+    #[test]
+    fn const_requires_equal() {
+        assert_syntax_error("const A", "expected token: SymbolEqual, but got token: Eof");
+        assert_syntax_error(
+            "const A := 1",
+            "expected token: SymbolEqual, but got token: SymbolColonEqual",
+        );
+    }
+
+    #[test]
+    fn throw_requires_argument() {
+        assert!(matches!(
+            parse_body("throw\n\"x\"").as_slice(),
+            [Node::ThrowStatement { argument, .. }]
+                if matches!(argument.as_ref(), Node::StringExpression { .. })
+        ));
+        assert_syntax_error("throw", "unexpected token: Eof");
+    }
+
+    #[test]
+    fn try_clauses() {
+        for (input, has_parameter, has_catch, has_finally) in [
+            ("try { } catch e { } finally { }", true, true, true),
+            ("try { } catch { }", false, true, false),
+            ("try { } finally { }", false, false, true),
+        ] {
+            let body: Vec<Node> = parse_body(input);
+
+            let [
+                Node::TryStatement {
+                    catch_parameter,
+                    catch_body,
+                    finally_body,
+                    ..
+                },
+            ] = body.as_slice()
+            else {
+                panic!("expected a try statement, but got {body:?}");
+            };
+
+            assert_eq!(catch_parameter.is_some(), has_parameter, "{input}");
+            assert_eq!(catch_body.is_some(), has_catch, "{input}");
+            assert_eq!(finally_body.is_some(), has_finally, "{input}");
+        }
+    }
+
+    #[test]
+    fn try_without_clauses() {
+        assert_syntax_error(
+            "try { }",
+            "expected token: KeywordCatch or token: KeywordFinally, but got token: Eof",
+        );
+    }
+
+    #[test]
+    fn defer_inside_blocks() {
+        assert!(matches!(
+            parse_func_body("f := func { defer close(file) }").as_slice(),
+            [Node::DeferStatement { argument, .. }]
+                if matches!(argument.as_ref(), Node::CallExpression { .. })
+        ));
+        assert_syntax_error("f := func { defer }", "unexpected token: SymbolRightBrace");
+    }
+
+    #[test]
+    fn top_level_defer() {
+        assert_syntax_error(
+            "defer f()",
+            "unexpected token: KeywordDefer, only allowed inside a block",
+        );
+    }
+
+    #[test]
+    fn defer_in_loop_body() {
+        assert_warnings(
+            "loop { defer f() }",
+            &["defer at the top level of a loop body runs at the end of every iteration"],
+        );
+        assert_warnings("loop { { defer f() } }", &[]);
+    }
+
+    #[test]
+    fn generators() {
+        for (input, is_generator) in [
+            ("f := func { yield }", true),
+            ("f := func { x := yield 1 }", true),
+            ("f := func { loop { yield } }", true),
+            ("f := |x| yield x", true),
+            ("f := func { g := func { yield } }", false),
+            ("f := func { return 1 }", false),
+        ] {
+            let argument: Node = parse_argument(input);
+
+            let Node::FuncExpression {
+                is_generator: generator,
+                ..
+            } = argument
+            else {
+                panic!("expected a func, but got {argument:?}");
+            };
+
+            assert_eq!(generator, is_generator, "{input}");
+        }
+    }
+
+    #[test]
+    fn yield_argument() {
+        let body: Vec<Node> = parse_func_body("f := func { x := yield 1\nyield; }");
+
+        let [
+            Node::AssignmentStatement { arguments, .. },
+            Node::ExpressionStatement { argument, .. },
+        ] = body.as_slice()
+        else {
+            panic!("expected an assignment and an expression, but got {body:?}");
+        };
+
+        assert!(matches!(
+            arguments[0].as_ref(),
+            Node::YieldExpression { argument: Some(_) }
+        ));
+        assert!(matches!(
+            argument.as_ref(),
+            Node::YieldExpression { argument: None }
+        ));
+    }
+
+    #[test]
+    fn yield_outside_func_body() {
+        for input in ["yield 1", "x := { yield }"] {
+            assert_syntax_error(
+                input,
+                "unexpected token: KeywordYield, only allowed inside a func body",
+            );
+        }
+    }
+
+    #[test]
+    fn primitive_annotations() {
+        for (input, type_name) in [
+            ("n: int := 5", "int"),
+            ("const MAX: float = 1.5", "float"),
+            ("s: string := \"a\"", "string"),
+        ] {
+            assert!(
+                matches!(
+                    parse_type_annotation(input),
+                    Node::PrimitiveType { name } if name.as_ref() == type_name
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn compound_annotations() {
+        assert!(matches!(
+            parse_type_annotation("x: int? := none"),
+            Node::OptionalType { argument }
+                if matches!(argument.as_ref(), Node::PrimitiveType { .. })
+        ));
+        assert!(matches!(
+            parse_type_annotation("x: (int | string)? := 1"),
+            Node::OptionalType { argument }
+                if matches!(argument.as_ref(), Node::UnionType { types } if types.len() == 2)
+        ));
+        assert!(matches!(
+            parse_type_annotation("p: table [name: string, age: int] := table []"),
+            Node::TableType { properties } if properties.len() == 2
+        ));
+    }
+
+    #[test]
+    fn func_type_return_does_not_extend_over_union() {
+        let type_annotation: Node = parse_type_annotation("x: func() -> int | none := none");
+
+        let Node::UnionType { types } = type_annotation else {
+            panic!("expected a union, but got {type_annotation:?}");
+        };
+
+        let [func_type, _] = types.as_slice() else {
+            panic!("expected two types, but got {types:?}");
+        };
+
+        assert!(matches!(
+            func_type.as_ref(),
+            Node::FuncType {
+                return_type: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parameter_and_return_annotations() {
+        let argument: Node = parse_argument("f := func (n: int, m, ...rest: any) -> int { n }");
+
+        let Node::FuncExpression {
+            parameter_types,
+            variadic_parameter_type,
+            return_type,
+            ..
+        } = argument
+        else {
+            panic!("expected a func, but got {argument:?}");
+        };
+
+        assert!(matches!(parameter_types.as_slice(), [Some(_), None]));
+        assert!(variadic_parameter_type.is_some());
+        assert!(return_type.is_some());
+    }
+
+    #[test]
+    fn invalid_annotations() {
+        assert_syntax_error("x: foo := 1", "unknown type: foo");
+        assert_syntax_error(
+            "f := |x: int| x",
+            "expected token: SymbolComma, but got token: SymbolColon",
+        );
+    }
+
+    #[test]
+    fn statement_lines() {
+        let body: Vec<Node> = parse_body("\nx := 1\n\nif x {\n    f();\n}");
+
+        let [
+            Node::AssignmentStatement { line: 2, .. },
+            Node::IfStatement {
+                consequent_body,
+                line: 4,
+                ..
+            },
+        ] = body.as_slice()
+        else {
+            panic!("expected statements on lines 2 and 4, but got {body:?}");
+        };
+
+        let Node::BlockStatement { body, line: 4, .. } = consequent_body.as_ref() else {
+            panic!("expected a block on line 4, but got {consequent_body:?}");
+        };
+
+        assert!(matches!(
+            body.as_slice(),
+            [statement] if matches!(statement.as_ref(), Node::ExpressionStatement { line: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn attributes_with_arguments() {
+        let body: Vec<Node> = parse_body("@deprecated(\"use bar\") @inline export f := 1");
+
+        let [Node::ExportStatement { attributes, .. }] = body.as_slice() else {
+            panic!("expected an export, but got {body:?}");
+        };
+
+        let [deprecated, inline] = attributes.as_slice() else {
+            panic!("expected two attributes, but got {attributes:?}");
+        };
+
+        assert!(matches!(
+            deprecated.as_ref(),
+            Node::Attribute { name, arguments }
+                if name.as_ref() == "deprecated" && arguments.len() == 1
+        ));
+        assert!(matches!(
+            inline.as_ref(),
+            Node::Attribute { name, arguments } if name.as_ref() == "inline" && arguments.is_empty()
+        ));
+    }
+
+    #[test]
+    fn attribute_arguments_start_on_same_line() {
+        let body: Vec<Node> = parse_body("@deprecated\n(\"x\")\nf := 1");
+
+        let [
+            Node::ExpressionStatement { attributes, .. },
+            Node::AssignmentStatement { .. },
+        ] = body.as_slice()
+        else {
+            panic!("expected an expression and an assignment, but got {body:?}");
+        };
+
+        assert!(matches!(
+            attributes.as_slice(),
+            [attribute] if matches!(attribute.as_ref(), Node::Attribute { arguments, .. }
+                if arguments.is_empty())
+        ));
+    }
+
+    #[test]
+    fn attributes_on_imports() {
+        assert!(matches!(
+            parse_body("@a(1, 2) import \"x\" as x").as_slice(),
+            [Node::ImportStatement { attributes, .. }] if attributes.len() == 1
+        ));
+    }
+
+    #[test]
+    fn attribute_without_statement() {
+        assert_syntax_error("@a", "unexpected token: Eof");
+        assert_syntax_error(
+            "@a(1 x := 1",
+            "expected token: SymbolComma, but got token: Identifier",
+        );
+    }
+
+    #[test]
+    fn doc_comments_on_declarations() {
+        assert!(matches!(
+            parse_body("/// adds\n/// numbers\nadd := func a, b { a + b }").as_slice(),
+            [Node::AssignmentStatement { doc_comment: Some(doc_comment), .. }]
+                if doc_comment.as_ref() == "adds\nnumbers"
+        ));
+    }
+
+    #[test]
+    fn doc_comments_skip_exports_and_attributes() {
+        let body: Vec<Node> = parse_body("/// doc\nexport @inline f := 1");
+
+        let [Node::ExportStatement { argument, .. }] = body.as_slice() else {
+            panic!("expected an export, but got {body:?}");
+        };
+
+        assert!(matches!(
+            argument.as_ref(),
+            Node::AssignmentStatement { doc_comment: Some(doc_comment), .. }
+                if doc_comment.as_ref() == "doc"
+        ));
+    }
+
+    #[test]
+    fn doc_comments_on_table_properties_and_funcs() {
+        let argument: Node =
+            parse_argument("t := table [\n/// field\nname: 1,\n/// method\nfunc greet self { }\n]");
+
+        let Node::TableExpression { properties } = argument else {
+            panic!("expected a table, but got {argument:?}");
+        };
+
+        let doc_comments: Vec<Option<&str>> = properties
+            .iter()
+            .map(|(_, _, _, doc_comment)| doc_comment.as_deref())
+            .collect();
+
+        assert_eq!(doc_comments, [Some("field"), Some("method")]);
+
+        let argument: Node = parse_argument("g(\n/// callback\nfunc { })");
+
+        let Node::CallExpression { arguments, .. } = argument else {
+            panic!("expected a call, but got {argument:?}");
+        };
+
+        assert!(matches!(
+            arguments[0].as_ref(),
+            Node::FuncExpression { doc_comment: Some(doc_comment), .. }
+                if doc_comment.as_ref() == "callback"
+        ));
+    }
+
+    #[test]
+    fn ignored_doc_comments() {
+        let body: Vec<Node> = parse_body("/// lost\nf()\nx := 1");
+
+        assert!(matches!(
+            body.as_slice(),
+            [
+                Node::ExpressionStatement { .. },
+                Node::AssignmentStatement {
+                    doc_comment: None,
+                    ..
+                },
+            ]
+        ));
     }
 }
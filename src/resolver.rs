@@ -0,0 +1,476 @@
+pub struct Resolver {
+    scopes: Vec<Vec<(std::rc::Rc<str>, bool)>>,
+    label_scopes: Vec<Vec<std::rc::Rc<str>>>,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Resolver {
+            scopes: Vec::new(),
+            label_scopes: Vec::new(),
+        }
+    }
+
+    pub fn resolve(
+        &mut self,
+        program: &super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        self.resolve_node(program, error_handler)
+    }
+
+    fn declare(
+        &mut self,
+        name: &std::rc::Rc<str>,
+        is_constant: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let scope: &mut Vec<(std::rc::Rc<str>, bool)> = self.scopes.last_mut()?;
+
+        if scope.iter().any(|(declared_name, is_declared_constant)| {
+            declared_name == name && *is_declared_constant
+        }) {
+            error_handler.set_error(super::error_handler::Error::ResolveError(format!(
+                "cannot redeclare constant: {name}"
+            )));
+
+            return None;
+        }
+
+        scope.push((name.clone(), is_constant));
+
+        Some(())
+    }
+
+    fn check_assignable(
+        &self,
+        name: &std::rc::Rc<str>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let is_constant: bool = self
+            .scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(declared_name, _)| declared_name == name)
+            .is_some_and(|(_, is_constant)| *is_constant);
+
+        if is_constant {
+            error_handler.set_error(super::error_handler::Error::ResolveError(format!(
+                "cannot assign to constant: {name}"
+            )));
+
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn check_label(
+        &self,
+        label: &Option<std::rc::Rc<str>>,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        if let Some(label) = label
+            && !self
+                .label_scopes
+                .last()
+                .is_some_and(|labels| labels.contains(label))
+        {
+            error_handler.set_error(super::error_handler::Error::ResolveError(format!(
+                "unknown loop label: '{label}"
+            )));
+
+            return None;
+        }
+
+        Some(())
+    }
+
+    fn resolve_target(
+        &mut self,
+        target: &super::node::Node,
+        is_decleration: bool,
+        is_constant: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        match target {
+            super::node::Node::IdentifierExpression { name }
+            | super::node::Node::IdentifierPattern { name } => {
+                if is_decleration {
+                    self.declare(name, is_constant, error_handler)
+                } else {
+                    self.check_assignable(name, error_handler)
+                }
+            }
+            super::node::Node::ArrayPattern { elements } => {
+                for element in elements {
+                    self.resolve_target(element, is_decleration, is_constant, error_handler)?;
+                }
+
+                Some(())
+            }
+            super::node::Node::TablePattern { properties } => {
+                for (_, value) in properties {
+                    self.resolve_target(value, is_decleration, is_constant, error_handler)?;
+                }
+
+                Some(())
+            }
+            super::node::Node::RestPattern { argument } => {
+                self.resolve_target(argument, is_decleration, is_constant, error_handler)
+            }
+            _ => self.resolve_node(target, error_handler),
+        }
+    }
+
+    fn resolve_nodes(
+        &mut self,
+        nodes: &[Box<super::node::Node>],
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        for node in nodes {
+            self.resolve_node(node, error_handler)?;
+        }
+
+        Some(())
+    }
+
+    fn resolve_node(
+        &mut self,
+        node: &super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        match node {
+            super::node::Node::ProgramStatement { body } => {
+                self.scopes.push(Vec::new());
+                self.label_scopes.push(Vec::new());
+                self.resolve_nodes(body, error_handler)?;
+                self.label_scopes.pop();
+                self.scopes.pop();
+            }
+            super::node::Node::BlockStatement { body, value, .. } => {
+                self.scopes.push(Vec::new());
+                self.resolve_nodes(body, error_handler)?;
+
+                if let Some(value) = value {
+                    self.resolve_node(value, error_handler)?;
+                }
+
+                self.scopes.pop();
+            }
+            super::node::Node::IfStatement {
+                argument,
+                consequent_body,
+                alternate_body,
+                ..
+            } => {
+                self.resolve_node(argument, error_handler)?;
+                self.resolve_node(consequent_body, error_handler)?;
+                self.resolve_node(alternate_body, error_handler)?;
+            }
+            super::node::Node::LoopStatement { label, body, .. } => {
+                if let Some(label) = label {
+                    self.label_scopes.last_mut()?.push(label.clone());
+                }
+
+                self.resolve_node(body, error_handler)?;
+
+                if label.is_some() {
+                    self.label_scopes.last_mut()?.pop();
+                }
+            }
+            super::node::Node::ReturnStatement { arguments, .. } => {
+                self.resolve_nodes(arguments, error_handler)?;
+            }
+            super::node::Node::BreakStatement {
+                label, argument, ..
+            } => {
+                self.check_label(label, error_handler)?;
+
+                if let Some(argument) = argument {
+                    self.resolve_node(argument, error_handler)?;
+                }
+            }
+            super::node::Node::AssignmentStatement {
+                targets,
+                arguments,
+                is_decleration,
+                is_constant,
+                ..
+            } => {
+                self.resolve_nodes(arguments, error_handler)?;
+
+                for target in targets {
+                    self.resolve_target(target, *is_decleration, *is_constant, error_handler)?;
+                }
+            }
+            super::node::Node::ExpressionStatement { argument, .. }
+            | super::node::Node::ThrowStatement { argument, .. }
+            | super::node::Node::DeferStatement { argument, .. }
+            | super::node::Node::ExportStatement { argument, .. }
+            | super::node::Node::PrefixExpression { argument, .. }
+            | super::node::Node::SpreadExpression { argument }
+            | super::node::Node::RestPattern { argument } => {
+                self.resolve_node(argument, error_handler)?;
+            }
+            super::node::Node::TryStatement {
+                body,
+                catch_parameter,
+                catch_body,
+                finally_body,
+                ..
+            } => {
+                self.resolve_node(body, error_handler)?;
+
+                if let Some(catch_body) = catch_body {
+                    self.scopes.push(Vec::new());
+
+                    if let Some(catch_parameter) = catch_parameter {
+                        self.resolve_target(catch_parameter, true, false, error_handler)?;
+                    }
+
+                    self.resolve_node(catch_body, error_handler)?;
+                    self.scopes.pop();
+                }
+
+                if let Some(finally_body) = finally_body {
+                    self.resolve_node(finally_body, error_handler)?;
+                }
+            }
+            super::node::Node::MatchStatement { argument, arms, .. } => {
+                self.resolve_node(argument, error_handler)?;
+                self.resolve_nodes(arms, error_handler)?;
+            }
+            super::node::Node::MatchArm {
+                pattern,
+                guard,
+                body,
+            } => {
+                self.scopes.push(Vec::new());
+                self.resolve_target(pattern, true, false, error_handler)?;
+
+                if let Some(guard) = guard {
+                    self.resolve_node(guard, error_handler)?;
+                }
+
+                self.resolve_node(body, error_handler)?;
+                self.scopes.pop();
+            }
+            super::node::Node::ImportStatement { alias, names, .. } => {
+                for name in alias.iter().chain(names.iter()) {
+                    self.resolve_target(name, true, false, error_handler)?;
+                }
+            }
+            super::node::Node::InterpolatedStringExpression { parts } => {
+                self.resolve_nodes(parts, error_handler)?;
+            }
+            super::node::Node::InfixExpression {
+                left_argument,
+                right_argument,
+                ..
+            } => {
+                self.resolve_node(left_argument, error_handler)?;
+                self.resolve_node(right_argument, error_handler)?;
+            }
+            super::node::Node::PipelineExpression { argument, target } => {
+                self.resolve_node(argument, error_handler)?;
+                self.resolve_node(target, error_handler)?;
+            }
+            super::node::Node::RangeExpression { start, end, .. } => {
+                for argument in start.iter().chain(end.iter()) {
+                    self.resolve_node(argument, error_handler)?;
+                }
+            }
+            super::node::Node::ArrayExpression { elements }
+            | super::node::Node::ArrayPattern { elements } => {
+                self.resolve_nodes(elements, error_handler)?;
+            }
+            super::node::Node::TableExpression { properties } => {
+                for (key, value, key_type, _) in properties {
+                    if key_type == &super::node::PropertyKeyType::Computed {
+                        self.resolve_node(key, error_handler)?;
+                    }
+
+                    self.resolve_node(value, error_handler)?;
+                }
+            }
+            super::node::Node::TablePattern { properties } => {
+                for (_, value) in properties {
+                    self.resolve_node(value, error_handler)?;
+                }
+            }
+            super::node::Node::MemberExpression {
+                target, argument, ..
+            } => {
+                self.resolve_node(target, error_handler)?;
+                self.resolve_node(argument, error_handler)?;
+            }
+            super::node::Node::FuncExpression {
+                parameters,
+                default_arguments,
+                variadic_parameter,
+                body,
+                ..
+            } => {
+                self.scopes.push(Vec::new());
+                self.label_scopes.push(Vec::new());
+
+                for default_argument in default_arguments.iter().flatten() {
+                    self.resolve_node(default_argument, error_handler)?;
+                }
+
+                for parameter in parameters.iter().chain(variadic_parameter.iter()) {
+                    self.resolve_target(parameter, true, false, error_handler)?;
+                }
+
+                self.resolve_node(body, error_handler)?;
+                self.label_scopes.pop();
+                self.scopes.pop();
+            }
+            super::node::Node::CallExpression {
+                target, arguments, ..
+            }
+            | super::node::Node::MethodCallExpression {
+                target, arguments, ..
+            } => {
+                self.resolve_node(target, error_handler)?;
+                self.resolve_nodes(arguments, error_handler)?;
+            }
+            super::node::Node::YieldExpression { argument } => {
+                if let Some(argument) = argument {
+                    self.resolve_node(argument, error_handler)?;
+                }
+            }
+            super::node::Node::ContinueStatement { label, .. } => {
+                self.check_label(label, error_handler)?;
+            }
+            super::node::Node::EmptyStatement
+            | super::node::Node::Attribute { .. }
+            | super::node::Node::LiteralExpression { .. }
+            | super::node::Node::IdentifierExpression { .. }
+            | super::node::Node::StringExpression { .. }
+            | super::node::Node::LiteralPattern { .. }
+            | super::node::Node::StringPattern { .. }
+            | super::node::Node::IdentifierPattern { .. }
+            | super::node::Node::WildcardPattern
+            | super::node::Node::PrimitiveType { .. }
+            | super::node::Node::TableType { .. }
+            | super::node::Node::FuncType { .. }
+            | super::node::Node::UnionType { .. }
+            | super::node::Node::OptionalType { .. } => {}
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::{Error, ErrorHandler};
+
+    fn resolve(input: &str) -> ErrorHandler {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let mut lexer: super::super::lexer::Lexer<'_> = super::super::lexer::Lexer::new(input);
+        let mut parser: super::super::parser::Parser<'_> =
+            super::super::parser::Parser::new(&mut lexer);
+
+        let program: super::super::node::Node = parser
+            .parse(&mut error_handler)
+            .unwrap_or_else(|| panic!("{input}: {:?}", error_handler.error()));
+
+        super::Resolver::new().resolve(&program, &mut error_handler);
+
+        error_handler
+    }
+
+    fn assert_resolve_error(input: &str, message: &str) {
+        assert_eq!(
+            resolve(input).error(),
+            Some(&Error::ResolveError(message.to_string())),
+            "{input}"
+        );
+    }
+
+    #[test]
+    fn labels_of_enclosing_loops() {
+        assert_eq!(
+            resolve("'a: loop { 'b: loop { break 'a; continue 'b } }").error(),
+            None
+        );
+    }
+
+    #[test]
+    fn unknown_labels() {
+        assert_resolve_error("loop { break 'a }", "unknown loop label: 'a");
+        assert_resolve_error(
+            "'a: loop { }\nloop { continue 'a }",
+            "unknown loop label: 'a",
+        );
+    }
+
+    #[test]
+    fn labels_do_not_cross_func_bodies() {
+        assert_resolve_error(
+            "'a: loop { f := func { break 'a } }",
+            "unknown loop label: 'a",
+        );
+        assert_eq!(
+            resolve("'a: loop { f := func { 'a: loop { break 'a } } }").error(),
+            None
+        );
+    }
+
+    #[test]
+    fn constant_reassignment() {
+        assert_resolve_error("const A = 1\nA = 2", "cannot assign to constant: A");
+        assert_resolve_error(
+            "const A = 1\nf := func { A = 3 }",
+            "cannot assign to constant: A",
+        );
+        assert_resolve_error(
+            "const [A, B] = [1, 2]\nB = 3",
+            "cannot assign to constant: B",
+        );
+        assert_resolve_error(
+            "const A = 1\n[x, A] = [1, 2]",
+            "cannot assign to constant: A",
+        );
+    }
+
+    #[test]
+    fn constant_redeclaration() {
+        assert_resolve_error("const A = 1\nA := 2", "cannot redeclare constant: A");
+        assert_resolve_error("const A = 1\nconst A = 2", "cannot redeclare constant: A");
+    }
+
+    #[test]
+    fn shadowed_constants() {
+        for input in [
+            "const A = 1\n{ A := 2\nA = 3 }",
+            "const A = 1\nf := func A { A = 2 }",
+            "a := 1\nconst a = 2",
+            "const T = table [a: 1]\nT.a = 2",
+        ] {
+            assert_eq!(resolve(input).error(), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn catch_parameter_scope() {
+        assert_eq!(
+            resolve("const e = 1\ntry { } catch e { e = 2 }").error(),
+            None
+        );
+        assert_resolve_error(
+            "const A = 1\ntry { A = 2 } finally { }",
+            "cannot assign to constant: A",
+        );
+    }
+}
@@ -1,7 +1,13 @@
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Default)]
 pub enum Token {
     Literal(std::rc::Rc<str>),
     Identifier(std::rc::Rc<str>),
+    String(std::rc::Rc<str>),
+    StringHead(std::rc::Rc<str>),
+    StringMiddle(std::rc::Rc<str>),
+    StringTail(std::rc::Rc<str>),
+    Label(std::rc::Rc<str>),
+    DocComment(std::rc::Rc<str>),
 
     KeywordIf,
     KeywordElse,
@@ -11,11 +17,22 @@ pub enum Token {
     KeywordReturn,
     KeywordBreak,
     KeywordContinue,
+    KeywordMatch,
+    KeywordImport,
+    KeywordExport,
+    KeywordConst,
+    KeywordThrow,
+    KeywordTry,
+    KeywordCatch,
+    KeywordFinally,
+    KeywordDefer,
+    KeywordYield,
 
     SymbolEqual,
     SymbolColonEqual,
     SymbolPlus,
     SymbolMinus,
+    SymbolMinusGreater,
     SymbolAsterisk,
     SymbolSlash,
     SymbolBang,
@@ -25,12 +42,14 @@ pub enum Token {
     SymbolLessLess,
     SymbolGreaterGreater,
     SymbolEqualEqual,
+    SymbolEqualGreater,
     SymbolLess,
     SymbolGreater,
     SymbolBangEqual,
     SymbolLessEqual,
     SymbolGreaterEqual,
     SymbolPipePipe,
+    SymbolPipeGreater,
     SymbolAmpersandAmpersand,
     SymbolLeftParenthesis,
     SymbolRightParenthesis,
@@ -40,25 +59,34 @@ pub enum Token {
     SymbolRightSquareBracket,
     SymbolComma,
     SymbolDot,
+    SymbolDotDot,
+    SymbolDotDotEqual,
+    SymbolDotDotDot,
+    SymbolQuestionDot,
+    SymbolQuestionLeftSquareBracket,
+    SymbolQuestion,
+    SymbolQuestionQuestion,
+    SymbolAt,
     SymbolColon,
     SymbolSemicolon,
 
     NewLine,
+    #[default]
     Eof,
 }
 
-impl Default for Token {
-    fn default() -> Self {
-        Token::Eof
-    }
-}
-
 impl Token {
     #[must_use]
     pub fn type_as_string(&self) -> &'static str {
         match self {
             Token::Literal(..) => "Literal",
             Token::Identifier(..) => "Identifier",
+            Token::String(..) => "String",
+            Token::StringHead(..) => "StringHead",
+            Token::StringMiddle(..) => "StringMiddle",
+            Token::StringTail(..) => "StringTail",
+            Token::Label(..) => "Label",
+            Token::DocComment(..) => "DocComment",
             Token::KeywordIf => "KeywordIf",
             Token::KeywordElse => "KeywordElse",
             Token::KeywordLoop => "KeywordLoop",
@@ -67,10 +95,21 @@ impl Token {
             Token::KeywordReturn => "KeywordReturn",
             Token::KeywordBreak => "KeywordBreak",
             Token::KeywordContinue => "KeywordContinue",
+            Token::KeywordMatch => "KeywordMatch",
+            Token::KeywordImport => "KeywordImport",
+            Token::KeywordExport => "KeywordExport",
+            Token::KeywordConst => "KeywordConst",
+            Token::KeywordThrow => "KeywordThrow",
+            Token::KeywordTry => "KeywordTry",
+            Token::KeywordCatch => "KeywordCatch",
+            Token::KeywordFinally => "KeywordFinally",
+            Token::KeywordDefer => "KeywordDefer",
+            Token::KeywordYield => "KeywordYield",
             Token::SymbolEqual => "SymbolEqual",
             Token::SymbolColonEqual => "SymbolColonEqual",
             Token::SymbolPlus => "SymbolPlus",
             Token::SymbolMinus => "SymbolMinus",
+            Token::SymbolMinusGreater => "SymbolMinusGreater",
             Token::SymbolAsterisk => "SymbolAsterisk",
             Token::SymbolSlash => "SymbolSlash",
             Token::SymbolBang => "SymbolBang",
@@ -80,12 +119,14 @@ impl Token {
             Token::SymbolLessLess => "SymbolLessLess",
             Token::SymbolGreaterGreater => "SymbolGreaterGreater",
             Token::SymbolEqualEqual => "SymbolEqualEqual",
+            Token::SymbolEqualGreater => "SymbolEqualGreater",
             Token::SymbolLess => "SymbolLess",
             Token::SymbolGreater => "SymbolGreater",
             Token::SymbolBangEqual => "SymbolBangEqual",
             Token::SymbolLessEqual => "SymbolLessEqual",
             Token::SymbolGreaterEqual => "SymbolGreaterEqual",
             Token::SymbolPipePipe => "SymbolPipePipe",
+            Token::SymbolPipeGreater => "SymbolPipeGreater",
             Token::SymbolAmpersandAmpersand => "SymbolAmpersandAmpersand",
             Token::SymbolLeftParenthesis => "SymbolLeftParenthesis",
             Token::SymbolRightParenthesis => "SymbolRightParenthesis",
@@ -95,6 +136,14 @@ impl Token {
             Token::SymbolRightSquareBracket => "SymbolRightSquareBracket",
             Token::SymbolComma => "SymbolComma",
             Token::SymbolDot => "SymbolDot",
+            Token::SymbolDotDot => "SymbolDotDot",
+            Token::SymbolDotDotEqual => "SymbolDotDotEqual",
+            Token::SymbolDotDotDot => "SymbolDotDotDot",
+            Token::SymbolQuestionDot => "SymbolQuestionDot",
+            Token::SymbolQuestionLeftSquareBracket => "SymbolQuestionLeftSquareBracket",
+            Token::SymbolQuestion => "SymbolQuestion",
+            Token::SymbolQuestionQuestion => "SymbolQuestionQuestion",
+            Token::SymbolAt => "SymbolAt",
             Token::SymbolColon => "SymbolColon",
             Token::SymbolSemicolon => "SymbolSemicolon",
             Token::NewLine => "NewLine",
@@ -0,0 +1,1419 @@
+#[derive(Clone, PartialEq)]
+enum Type {
+    Dynamic,
+    Int,
+    Float,
+    String,
+    Bool,
+    None,
+    Table(Vec<(std::rc::Rc<str>, Type)>),
+    Func {
+        parameter_types: Vec<Type>,
+        required_parameter_count: usize,
+        variadic_parameter_type: Option<Box<Type>>,
+        return_type: Box<Type>,
+    },
+    Union(Vec<Type>),
+}
+
+impl Type {
+    fn union(types: Vec<Type>) -> Type {
+        let mut members: Vec<Type> = Vec::new();
+
+        for member in types {
+            let flattened: Vec<Type> = match member {
+                Type::Dynamic => return Type::Dynamic,
+                Type::Union(types) => types,
+                member => vec![member],
+            };
+
+            for member in flattened {
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+        }
+
+        if members.len() == 1 {
+            return members.pop().unwrap_or(Type::Dynamic);
+        }
+
+        Type::Union(members)
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Type::Int | Type::Float)
+    }
+
+    fn is_unchecked(&self) -> bool {
+        matches!(self, Type::Dynamic | Type::Union(..))
+    }
+
+    fn type_as_string(&self) -> String {
+        match self {
+            Type::Dynamic => "any".to_string(),
+            Type::Int => "int".to_string(),
+            Type::Float => "float".to_string(),
+            Type::String => "string".to_string(),
+            Type::Bool => "bool".to_string(),
+            Type::None => "none".to_string(),
+            Type::Table(properties) if properties.is_empty() => "table".to_string(),
+            Type::Table(properties) => {
+                let properties: Vec<String> = properties
+                    .iter()
+                    .map(|(name, value_type)| format!("{name}: {}", value_type.type_as_string()))
+                    .collect();
+
+                format!("table [{}]", properties.join(", "))
+            }
+            Type::Func {
+                parameter_types,
+                variadic_parameter_type,
+                return_type,
+                ..
+            } => {
+                let parameters: Vec<String> = parameter_types
+                    .iter()
+                    .map(Type::type_as_string)
+                    .chain(
+                        variadic_parameter_type
+                            .iter()
+                            .map(|variadic_parameter_type| {
+                                format!("...{}", variadic_parameter_type.type_as_string())
+                            }),
+                    )
+                    .collect();
+
+                format!(
+                    "func({}) -> {}",
+                    parameters.join(", "),
+                    return_type.type_as_string()
+                )
+            }
+            Type::Union(types) => {
+                let types: Vec<String> = types
+                    .iter()
+                    .map(|member| match member {
+                        Type::Func { .. } => format!("({})", member.type_as_string()),
+                        _ => member.type_as_string(),
+                    })
+                    .collect();
+
+                types.join(" | ")
+            }
+        }
+    }
+}
+
+struct Binding {
+    name: std::rc::Rc<str>,
+    value_type: Type,
+    is_annotated: bool,
+}
+
+struct Scope {
+    bindings: Vec<Binding>,
+    reassigned_names: Vec<std::rc::Rc<str>>,
+    redeclared_names: Vec<std::rc::Rc<str>>,
+    is_func_body: bool,
+}
+
+pub struct TypeChecker {
+    scopes: Vec<Scope>,
+    return_types: Vec<Option<Type>>,
+    line: usize,
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeChecker {
+    pub fn new() -> Self {
+        TypeChecker {
+            scopes: Vec::new(),
+            return_types: Vec::new(),
+            line: 0,
+        }
+    }
+
+    pub fn check(
+        &mut self,
+        program: &super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        self.check_node(program, error_handler)?;
+
+        Some(())
+    }
+
+    fn push_scope(&mut self, is_func_body: bool, block: Option<&super::node::Node>) {
+        let mut reassigned_names: Vec<std::rc::Rc<str>> = Vec::new();
+        let mut redeclared_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+        if let Some(block) = block {
+            Self::collect_reassigned_names(block, &mut reassigned_names);
+        }
+
+        if let Some(
+            super::node::Node::ProgramStatement { body }
+            | super::node::Node::BlockStatement { body, .. },
+        ) = block
+        {
+            let mut declared_names: Vec<std::rc::Rc<str>> = Vec::new();
+
+            for statement in body {
+                let statement: &super::node::Node = match statement.as_ref() {
+                    super::node::Node::ExportStatement { argument, .. } => argument,
+                    statement => statement,
+                };
+
+                if let super::node::Node::AssignmentStatement {
+                    targets,
+                    is_decleration: true,
+                    ..
+                } = statement
+                {
+                    for target in targets {
+                        Self::collect_target_names(target, &mut declared_names);
+                    }
+                }
+            }
+
+            for (index, name) in declared_names.iter().enumerate() {
+                if declared_names[..index].contains(name) && !redeclared_names.contains(name) {
+                    redeclared_names.push(name.clone());
+                }
+            }
+        }
+
+        self.scopes.push(Scope {
+            bindings: Vec::new(),
+            reassigned_names,
+            redeclared_names,
+            is_func_body,
+        });
+    }
+
+    fn collect_reassigned_names(
+        node: &super::node::Node,
+        reassigned_names: &mut Vec<std::rc::Rc<str>>,
+    ) {
+        let children: Vec<&super::node::Node> = match node {
+            super::node::Node::AssignmentStatement {
+                targets,
+                arguments,
+                is_decleration,
+                ..
+            } => {
+                if !is_decleration {
+                    for target in targets {
+                        Self::collect_target_names(target, reassigned_names);
+                    }
+                }
+
+                targets
+                    .iter()
+                    .chain(arguments.iter())
+                    .map(AsRef::as_ref)
+                    .collect()
+            }
+            super::node::Node::ProgramStatement { body: nodes }
+            | super::node::Node::ReturnStatement {
+                arguments: nodes, ..
+            }
+            | super::node::Node::MatchStatement { arms: nodes, .. }
+            | super::node::Node::InterpolatedStringExpression { parts: nodes }
+            | super::node::Node::ArrayExpression { elements: nodes } => {
+                nodes.iter().map(AsRef::as_ref).collect()
+            }
+            super::node::Node::BlockStatement { body, value, .. } => {
+                body.iter().chain(value.iter()).map(AsRef::as_ref).collect()
+            }
+            super::node::Node::IfStatement {
+                argument,
+                consequent_body,
+                alternate_body,
+                ..
+            } => vec![argument, consequent_body, alternate_body],
+            super::node::Node::LoopStatement { body: argument, .. }
+            | super::node::Node::ExpressionStatement { argument, .. }
+            | super::node::Node::ThrowStatement { argument, .. }
+            | super::node::Node::DeferStatement { argument, .. }
+            | super::node::Node::ExportStatement { argument, .. }
+            | super::node::Node::PrefixExpression { argument, .. }
+            | super::node::Node::SpreadExpression { argument } => vec![argument],
+            super::node::Node::BreakStatement { argument, .. }
+            | super::node::Node::YieldExpression { argument } => {
+                argument.iter().map(AsRef::as_ref).collect()
+            }
+            super::node::Node::TryStatement {
+                body,
+                catch_body,
+                finally_body,
+                ..
+            } => std::iter::once(body)
+                .chain(catch_body.iter())
+                .chain(finally_body.iter())
+                .map(AsRef::as_ref)
+                .collect(),
+            super::node::Node::MatchArm { guard, body, .. } => std::iter::once(body)
+                .chain(guard.iter())
+                .map(AsRef::as_ref)
+                .collect(),
+            super::node::Node::InfixExpression {
+                left_argument: argument,
+                right_argument: target,
+                ..
+            }
+            | super::node::Node::PipelineExpression { argument, target }
+            | super::node::Node::MemberExpression {
+                target, argument, ..
+            } => vec![argument, target],
+            super::node::Node::RangeExpression { start, end, .. } => {
+                start.iter().chain(end.iter()).map(AsRef::as_ref).collect()
+            }
+            super::node::Node::TableExpression { properties } => properties
+                .iter()
+                .flat_map(|(key, value, _, _)| [key.as_ref(), value.as_ref()])
+                .collect(),
+            super::node::Node::FuncExpression {
+                default_arguments,
+                body,
+                ..
+            } => default_arguments
+                .iter()
+                .flatten()
+                .chain(std::iter::once(body))
+                .map(AsRef::as_ref)
+                .collect(),
+            super::node::Node::CallExpression {
+                target, arguments, ..
+            }
+            | super::node::Node::MethodCallExpression {
+                target, arguments, ..
+            } => std::iter::once(target)
+                .chain(arguments.iter())
+                .map(AsRef::as_ref)
+                .collect(),
+            super::node::Node::ContinueStatement { .. }
+            | super::node::Node::ImportStatement { .. }
+            | super::node::Node::EmptyStatement
+            | super::node::Node::Attribute { .. }
+            | super::node::Node::LiteralExpression { .. }
+            | super::node::Node::IdentifierExpression { .. }
+            | super::node::Node::StringExpression { .. }
+            | super::node::Node::LiteralPattern { .. }
+            | super::node::Node::StringPattern { .. }
+            | super::node::Node::IdentifierPattern { .. }
+            | super::node::Node::WildcardPattern
+            | super::node::Node::ArrayPattern { .. }
+            | super::node::Node::RestPattern { .. }
+            | super::node::Node::TablePattern { .. }
+            | super::node::Node::PrimitiveType { .. }
+            | super::node::Node::TableType { .. }
+            | super::node::Node::FuncType { .. }
+            | super::node::Node::UnionType { .. }
+            | super::node::Node::OptionalType { .. } => Vec::new(),
+        };
+
+        for child in children {
+            Self::collect_reassigned_names(child, reassigned_names);
+        }
+    }
+
+    fn collect_target_names(
+        target: &super::node::Node,
+        reassigned_names: &mut Vec<std::rc::Rc<str>>,
+    ) {
+        match target {
+            super::node::Node::IdentifierExpression { name }
+            | super::node::Node::IdentifierPattern { name } => reassigned_names.push(name.clone()),
+            super::node::Node::ArrayPattern { elements } => {
+                for element in elements {
+                    Self::collect_target_names(element, reassigned_names);
+                }
+            }
+            super::node::Node::TablePattern { properties } => {
+                for (_, value) in properties {
+                    Self::collect_target_names(value, reassigned_names);
+                }
+            }
+            super::node::Node::RestPattern { argument } => {
+                Self::collect_target_names(argument, reassigned_names);
+            }
+            _ => {}
+        }
+    }
+
+    fn declare(&mut self, name: &std::rc::Rc<str>, value_type: Type, is_annotated: bool) {
+        if let Some(scope) = self.scopes.last_mut() {
+            let value_type: Type = if !is_annotated
+                && (scope.reassigned_names.contains(name) || matches!(value_type, Type::Table(..)))
+            {
+                Type::Dynamic
+            } else {
+                value_type
+            };
+
+            scope.bindings.push(Binding {
+                name: name.clone(),
+                value_type,
+                is_annotated,
+            });
+        }
+    }
+
+    fn lookup(&self, name: &std::rc::Rc<str>) -> Type {
+        let mut is_outer_func: bool = false;
+
+        for scope in self.scopes.iter().rev() {
+            if let Some(binding) = scope
+                .bindings
+                .iter()
+                .rev()
+                .find(|binding| &binding.name == name)
+            {
+                if is_outer_func && scope.redeclared_names.contains(name) {
+                    return Type::Dynamic;
+                }
+
+                return binding.value_type.clone();
+            }
+
+            is_outer_func |= scope.is_func_body;
+        }
+
+        Type::Dynamic
+    }
+
+    fn assign(
+        &mut self,
+        name: &std::rc::Rc<str>,
+        value_type: Type,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let mut is_outer_func: bool = false;
+
+        for scope in self.scopes.iter_mut().rev() {
+            if let Some(binding) = scope
+                .bindings
+                .iter_mut()
+                .rev()
+                .find(|binding| &binding.name == name)
+            {
+                if binding.is_annotated {
+                    if !Self::is_assignable(&value_type, &binding.value_type) {
+                        error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                            "line {}: type mismatch: cannot assign {} to {name}: {}",
+                            self.line,
+                            value_type.type_as_string(),
+                            binding.value_type.type_as_string()
+                        )));
+
+                        return None;
+                    }
+                } else if is_outer_func || binding.value_type != value_type {
+                    binding.value_type = Type::Dynamic;
+                }
+
+                return Some(());
+            }
+
+            is_outer_func |= scope.is_func_body;
+        }
+
+        Some(())
+    }
+
+    fn annotation_type(annotation: &super::node::Node) -> Type {
+        match annotation {
+            super::node::Node::PrimitiveType { name } => match name.as_ref() {
+                "int" => Type::Int,
+                "float" => Type::Float,
+                "string" => Type::String,
+                "bool" => Type::Bool,
+                "none" => Type::None,
+                _ => Type::Dynamic,
+            },
+            super::node::Node::TableType { properties } => Type::Table(
+                properties
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Self::annotation_type(value)))
+                    .collect(),
+            ),
+            super::node::Node::FuncType {
+                parameter_types,
+                return_type,
+            } => Type::Func {
+                parameter_types: parameter_types
+                    .iter()
+                    .map(|parameter_type| Self::annotation_type(parameter_type))
+                    .collect(),
+                required_parameter_count: parameter_types.len(),
+                variadic_parameter_type: None,
+                return_type: Box::new(Self::optional_annotation_type(return_type)),
+            },
+            super::node::Node::UnionType { types } => Type::union(
+                types
+                    .iter()
+                    .map(|member| Self::annotation_type(member))
+                    .collect(),
+            ),
+            super::node::Node::OptionalType { argument } => {
+                Type::union(vec![Self::annotation_type(argument), Type::None])
+            }
+            _ => Type::Dynamic,
+        }
+    }
+
+    fn optional_annotation_type(annotation: &Option<Box<super::node::Node>>) -> Type {
+        annotation.as_ref().map_or(Type::Dynamic, |annotation| {
+            Self::annotation_type(annotation)
+        })
+    }
+
+    fn is_assignable(value_type: &Type, target_type: &Type) -> bool {
+        match (value_type, target_type) {
+            (Type::Dynamic, _) | (_, Type::Dynamic) => true,
+            (Type::Union(types), _) => types
+                .iter()
+                .all(|member| Self::is_assignable(member, target_type)),
+            (_, Type::Union(types)) => types
+                .iter()
+                .any(|member| Self::is_assignable(value_type, member)),
+            (Type::Int, Type::Float) => true,
+            (Type::Table(properties), Type::Table(target_properties)) => target_properties
+                .iter()
+                .all(|(target_name, target_value_type)| {
+                    properties.iter().any(|(name, value_type)| {
+                        name == target_name && Self::is_assignable(value_type, target_value_type)
+                    })
+                }),
+            (
+                Type::Func {
+                    parameter_types,
+                    required_parameter_count,
+                    variadic_parameter_type,
+                    return_type,
+                },
+                Type::Func {
+                    parameter_types: target_parameter_types,
+                    return_type: target_return_type,
+                    ..
+                },
+            ) => {
+                target_parameter_types.len() >= *required_parameter_count
+                    && (variadic_parameter_type.is_some()
+                        || target_parameter_types.len() <= parameter_types.len())
+                    && target_parameter_types
+                        .iter()
+                        .zip(parameter_types.iter())
+                        .all(|(target_parameter_type, parameter_type)| {
+                            Self::is_assignable(target_parameter_type, parameter_type)
+                        })
+                    && Self::is_assignable(return_type, target_return_type)
+            }
+            _ => value_type == target_type,
+        }
+    }
+
+    fn literal_type(value: &str) -> Type {
+        match value {
+            "true" | "false" => Type::Bool,
+            "none" => Type::None,
+            _ if value.contains('.') => Type::Float,
+            _ => Type::Int,
+        }
+    }
+
+    fn bind_target(
+        &mut self,
+        target: &super::node::Node,
+        value_type: Type,
+        is_decleration: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        match target {
+            super::node::Node::IdentifierExpression { name }
+            | super::node::Node::IdentifierPattern { name } => {
+                if is_decleration {
+                    self.declare(name, value_type, false);
+
+                    Some(())
+                } else {
+                    self.assign(name, value_type, error_handler)
+                }
+            }
+            super::node::Node::ArrayPattern { elements } => {
+                for element in elements {
+                    self.bind_target(element, Type::Dynamic, is_decleration, error_handler)?;
+                }
+
+                Some(())
+            }
+            super::node::Node::TablePattern { properties } => {
+                for (_, value) in properties {
+                    self.bind_target(value, Type::Dynamic, is_decleration, error_handler)?;
+                }
+
+                Some(())
+            }
+            super::node::Node::RestPattern { argument } => {
+                self.bind_target(argument, Type::Dynamic, is_decleration, error_handler)
+            }
+            super::node::Node::MemberExpression {
+                target,
+                argument,
+                notation_type: super::node::MemberNotationType::Dot,
+            } => {
+                let target_type: Type = self.check_node(target, error_handler)?;
+
+                if let (super::node::Node::IdentifierExpression { name }, Type::Table(properties)) =
+                    (argument.as_ref(), target_type)
+                    && let Some((_, property_type)) = properties
+                        .iter()
+                        .find(|(property_name, _)| property_name == name)
+                    && !Self::is_assignable(&value_type, property_type)
+                {
+                    error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                        "line {}: type mismatch: cannot assign {} to field {name}: {}",
+                        self.line,
+                        value_type.type_as_string(),
+                        property_type.type_as_string()
+                    )));
+
+                    return None;
+                }
+
+                Some(())
+            }
+            super::node::Node::WildcardPattern
+            | super::node::Node::LiteralPattern { .. }
+            | super::node::Node::StringPattern { .. } => Some(()),
+            _ => {
+                self.check_node(target, error_handler)?;
+
+                Some(())
+            }
+        }
+    }
+
+    fn check_nodes(
+        &mut self,
+        nodes: &[Box<super::node::Node>],
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Vec<Type>> {
+        nodes
+            .iter()
+            .map(|node| self.check_node(node, error_handler))
+            .collect()
+    }
+
+    fn check_assignment_statement(
+        &mut self,
+        targets: &[Box<super::node::Node>],
+        arguments: &[Box<super::node::Node>],
+        type_annotations: &[Option<Box<super::node::Node>>],
+        is_decleration: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let argument_types: Vec<Type> = self.check_nodes(arguments, error_handler)?;
+
+        for (index, target) in targets.iter().enumerate() {
+            let value_type: Type = if argument_types.len() == targets.len() {
+                argument_types[index].clone()
+            } else {
+                Type::Dynamic
+            };
+
+            let annotation: Option<&Box<super::node::Node>> =
+                type_annotations.get(index).and_then(Option::as_ref);
+
+            match (target.as_ref(), annotation) {
+                (super::node::Node::IdentifierExpression { name }, Some(annotation)) => {
+                    let annotation_type: Type = Self::annotation_type(annotation);
+
+                    if !Self::is_assignable(&value_type, &annotation_type) {
+                        error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                            "line {}: type mismatch: cannot assign {} to {name}: {}",
+                            self.line,
+                            value_type.type_as_string(),
+                            annotation_type.type_as_string()
+                        )));
+
+                        return None;
+                    }
+
+                    self.declare(name, annotation_type, true);
+                }
+                _ => self.bind_target(target, value_type, is_decleration, error_handler)?,
+            }
+        }
+
+        Some(())
+    }
+
+    fn check_return_statement(
+        &mut self,
+        arguments: &[Box<super::node::Node>],
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<()> {
+        let argument_types: Vec<Type> = self.check_nodes(arguments, error_handler)?;
+
+        let Some(Some(return_type)) = self.return_types.last() else {
+            return Some(());
+        };
+
+        if argument_types.len() > 1 {
+            error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                "line {}: expected 1 return value, but got {}",
+                self.line,
+                argument_types.len()
+            )));
+
+            return None;
+        }
+
+        let argument_type: &Type = argument_types.first().unwrap_or(&Type::None);
+
+        if !Self::is_assignable(argument_type, return_type) {
+            error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                "line {}: type mismatch: expected return type {}, but got {}",
+                self.line,
+                return_type.type_as_string(),
+                argument_type.type_as_string()
+            )));
+
+            return None;
+        }
+
+        Some(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_func_expression(
+        &mut self,
+        parameters: &[Box<super::node::Node>],
+        default_arguments: &[Option<Box<super::node::Node>>],
+        variadic_parameter: &Option<Box<super::node::Node>>,
+        parameter_types: &[Option<Box<super::node::Node>>],
+        variadic_parameter_type: &Option<Box<super::node::Node>>,
+        return_type: &Option<Box<super::node::Node>>,
+        body: &super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Type> {
+        let parameter_types: Vec<Option<Type>> = parameter_types
+            .iter()
+            .map(|parameter_type| {
+                parameter_type
+                    .as_ref()
+                    .map(|parameter_type| Self::annotation_type(parameter_type))
+            })
+            .collect();
+
+        for (index, default_argument) in default_arguments.iter().enumerate() {
+            let Some(default_argument) = default_argument else {
+                continue;
+            };
+
+            let argument_type: Type = self.check_node(default_argument, error_handler)?;
+
+            if let Some(Some(parameter_type)) = parameter_types.get(index)
+                && !Self::is_assignable(&argument_type, parameter_type)
+            {
+                error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                    "line {}: type mismatch: expected {}, but got {} for default argument {}",
+                    self.line,
+                    parameter_type.type_as_string(),
+                    argument_type.type_as_string(),
+                    index + 1
+                )));
+
+                return None;
+            }
+        }
+
+        let return_type: Option<Type> = return_type
+            .as_ref()
+            .map(|return_type| Self::annotation_type(return_type));
+
+        self.push_scope(true, Some(body));
+
+        for (parameter, parameter_type) in parameters.iter().zip(parameter_types.iter()) {
+            if let super::node::Node::IdentifierExpression { name } = parameter.as_ref() {
+                self.declare(
+                    name,
+                    parameter_type.clone().unwrap_or(Type::Dynamic),
+                    parameter_type.is_some(),
+                );
+            }
+        }
+
+        if let Some(variadic_parameter) = variadic_parameter {
+            self.bind_target(variadic_parameter, Type::Dynamic, true, error_handler)?;
+        }
+
+        self.return_types.push(return_type.clone());
+
+        let value_type: Type = self.check_node(body, error_handler)?;
+
+        self.return_types.pop();
+        self.scopes.pop();
+
+        if let super::node::Node::BlockStatement {
+            value: Some(..), ..
+        } = body
+            && let Some(return_type) = &return_type
+            && !Self::is_assignable(&value_type, return_type)
+        {
+            error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                "line {}: type mismatch: expected return type {}, but got {}",
+                self.line,
+                return_type.type_as_string(),
+                value_type.type_as_string()
+            )));
+
+            return None;
+        }
+
+        Some(Type::Func {
+            required_parameter_count: default_arguments
+                .iter()
+                .take_while(|default_argument| default_argument.is_none())
+                .count(),
+            parameter_types: parameter_types
+                .into_iter()
+                .map(|parameter_type| parameter_type.unwrap_or(Type::Dynamic))
+                .collect(),
+            variadic_parameter_type: variadic_parameter
+                .as_ref()
+                .map(|_| Box::new(Self::optional_annotation_type(variadic_parameter_type))),
+            return_type: Box::new(return_type.unwrap_or(Type::Dynamic)),
+        })
+    }
+
+    fn check_call_expression(
+        &mut self,
+        target: &super::node::Node,
+        arguments: &[Box<super::node::Node>],
+        is_optional: bool,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Type> {
+        let target_type: Type = self.check_node(target, error_handler)?;
+        let argument_types: Vec<Type> = self.check_nodes(arguments, error_handler)?;
+
+        let callee: String = match target {
+            super::node::Node::IdentifierExpression { name } => name.to_string(),
+            _ => "func".to_string(),
+        };
+
+        match target_type {
+            Type::Func {
+                parameter_types,
+                required_parameter_count,
+                variadic_parameter_type,
+                return_type,
+            } => {
+                if arguments.iter().any(|argument| {
+                    matches!(
+                        argument.as_ref(),
+                        super::node::Node::SpreadExpression { .. }
+                    )
+                }) {
+                    return Some(*return_type);
+                }
+
+                if argument_types.len() < required_parameter_count
+                    || (variadic_parameter_type.is_none()
+                        && argument_types.len() > parameter_types.len())
+                {
+                    let expected: String = if variadic_parameter_type.is_some() {
+                        format!("at least {required_parameter_count}")
+                    } else if required_parameter_count == parameter_types.len() {
+                        required_parameter_count.to_string()
+                    } else {
+                        format!("{required_parameter_count} to {}", parameter_types.len())
+                    };
+
+                    error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                        "line {}: expected {expected} arguments, but got {} in call to {callee}",
+                        self.line,
+                        argument_types.len()
+                    )));
+
+                    return None;
+                }
+
+                for (index, argument_type) in argument_types.iter().enumerate() {
+                    let Some(parameter_type) = parameter_types
+                        .get(index)
+                        .or(variadic_parameter_type.as_deref())
+                    else {
+                        continue;
+                    };
+
+                    if !Self::is_assignable(argument_type, parameter_type) {
+                        error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                            "line {}: type mismatch: expected {}, but got {} for argument {} of {callee}",
+                            self.line,
+                            parameter_type.type_as_string(),
+                            argument_type.type_as_string(),
+                            index + 1
+                        )));
+
+                        return None;
+                    }
+                }
+
+                Some(*return_type)
+            }
+            Type::None if is_optional => Some(Type::None),
+            Type::Int | Type::Float | Type::String | Type::Bool | Type::None => {
+                error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                    "line {}: cannot call value of type {}",
+                    self.line,
+                    target_type.type_as_string()
+                )));
+
+                None
+            }
+            _ => Some(Type::Dynamic),
+        }
+    }
+
+    fn check_infix_expression(
+        &self,
+        left_type: &Type,
+        right_type: &Type,
+        operator: &super::token::Token,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Type> {
+        let result_type: Option<Type> = match operator {
+            super::token::Token::SymbolEqualEqual | super::token::Token::SymbolBangEqual => {
+                Some(Type::Bool)
+            }
+            super::token::Token::SymbolPipePipe | super::token::Token::SymbolAmpersandAmpersand => {
+                Some(if left_type == &Type::Bool && right_type == &Type::Bool {
+                    Type::Bool
+                } else {
+                    Type::Dynamic
+                })
+            }
+            super::token::Token::SymbolQuestionQuestion => Some(match left_type {
+                Type::None => right_type.clone(),
+                Type::Union(types) => Type::union(
+                    types
+                        .iter()
+                        .filter(|member| member != &&Type::None)
+                        .chain(std::iter::once(right_type))
+                        .cloned()
+                        .collect(),
+                ),
+                left_type => left_type.clone(),
+            }),
+            _ if left_type.is_unchecked() || right_type.is_unchecked() => match operator {
+                super::token::Token::SymbolLess
+                | super::token::Token::SymbolGreater
+                | super::token::Token::SymbolLessEqual
+                | super::token::Token::SymbolGreaterEqual => Some(Type::Bool),
+                _ => Some(Type::Dynamic),
+            },
+            super::token::Token::SymbolPlus
+                if left_type == &Type::String && right_type == &Type::String =>
+            {
+                Some(Type::String)
+            }
+            super::token::Token::SymbolPlus
+            | super::token::Token::SymbolMinus
+            | super::token::Token::SymbolAsterisk
+                if left_type.is_numeric() && right_type.is_numeric() =>
+            {
+                Some(if left_type == &Type::Int && right_type == &Type::Int {
+                    Type::Int
+                } else {
+                    Type::Float
+                })
+            }
+            super::token::Token::SymbolSlash
+                if left_type.is_numeric() && right_type.is_numeric() =>
+            {
+                Some(Type::Float)
+            }
+            super::token::Token::SymbolPipe
+            | super::token::Token::SymbolAmpersand
+            | super::token::Token::SymbolCaret
+            | super::token::Token::SymbolLessLess
+            | super::token::Token::SymbolGreaterGreater
+                if left_type == &Type::Int && right_type == &Type::Int =>
+            {
+                Some(Type::Int)
+            }
+            super::token::Token::SymbolLess
+            | super::token::Token::SymbolGreater
+            | super::token::Token::SymbolLessEqual
+            | super::token::Token::SymbolGreaterEqual
+                if (left_type.is_numeric() && right_type.is_numeric())
+                    || (left_type == &Type::String && right_type == &Type::String) =>
+            {
+                Some(Type::Bool)
+            }
+            _ => None,
+        };
+
+        if result_type.is_none() {
+            error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                "line {}: unsupported operand types for {}: {} and {}",
+                self.line,
+                operator.type_as_string(),
+                left_type.type_as_string(),
+                right_type.type_as_string()
+            )));
+        }
+
+        result_type
+    }
+
+    fn check_node(
+        &mut self,
+        node: &super::node::Node,
+        error_handler: &mut super::error_handler::ErrorHandler,
+    ) -> Option<Type> {
+        let line: usize = self.line;
+
+        if let super::node::Node::BlockStatement { line, .. }
+        | super::node::Node::IfStatement { line, .. }
+        | super::node::Node::LoopStatement { line, .. }
+        | super::node::Node::ReturnStatement { line, .. }
+        | super::node::Node::BreakStatement { line, .. }
+        | super::node::Node::ContinueStatement { line, .. }
+        | super::node::Node::AssignmentStatement { line, .. }
+        | super::node::Node::ExpressionStatement { line, .. }
+        | super::node::Node::ThrowStatement { line, .. }
+        | super::node::Node::DeferStatement { line, .. }
+        | super::node::Node::TryStatement { line, .. }
+        | super::node::Node::MatchStatement { line, .. }
+        | super::node::Node::ImportStatement { line, .. }
+        | super::node::Node::ExportStatement { line, .. } = node
+        {
+            self.line = *line;
+        }
+
+        let node_type: Type = match node {
+            super::node::Node::ProgramStatement { body } => {
+                self.push_scope(true, Some(node));
+                self.check_nodes(body, error_handler)?;
+                self.scopes.pop();
+
+                Type::Dynamic
+            }
+            super::node::Node::BlockStatement { body, value, .. } => {
+                self.push_scope(false, Some(node));
+                self.check_nodes(body, error_handler)?;
+
+                let value_type: Type = match value {
+                    Some(value) => self.check_node(value, error_handler)?,
+                    None => Type::Dynamic,
+                };
+
+                self.scopes.pop();
+
+                value_type
+            }
+            super::node::Node::IfStatement {
+                argument,
+                consequent_body,
+                alternate_body,
+                ..
+            } => {
+                self.check_node(argument, error_handler)?;
+
+                let consequent_type: Type = self.check_node(consequent_body, error_handler)?;
+                let alternate_type: Type = self.check_node(alternate_body, error_handler)?;
+
+                if consequent_type == alternate_type {
+                    consequent_type
+                } else {
+                    Type::Dynamic
+                }
+            }
+            super::node::Node::LoopStatement { body, .. } => {
+                self.check_node(body, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::ReturnStatement { arguments, .. } => {
+                self.check_return_statement(arguments, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::BreakStatement { argument, .. }
+            | super::node::Node::YieldExpression { argument } => {
+                if let Some(argument) = argument {
+                    self.check_node(argument, error_handler)?;
+                }
+
+                Type::Dynamic
+            }
+            super::node::Node::AssignmentStatement {
+                targets,
+                arguments,
+                type_annotations,
+                is_decleration,
+                ..
+            } => {
+                self.check_assignment_statement(
+                    targets,
+                    arguments,
+                    type_annotations,
+                    *is_decleration,
+                    error_handler,
+                )?;
+
+                Type::Dynamic
+            }
+            super::node::Node::ExpressionStatement { argument, .. }
+            | super::node::Node::ThrowStatement { argument, .. }
+            | super::node::Node::DeferStatement { argument, .. }
+            | super::node::Node::ExportStatement { argument, .. }
+            | super::node::Node::SpreadExpression { argument } => {
+                self.check_node(argument, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::TryStatement {
+                body,
+                catch_parameter,
+                catch_body,
+                finally_body,
+                ..
+            } => {
+                self.check_node(body, error_handler)?;
+
+                if let Some(catch_body) = catch_body {
+                    self.push_scope(false, None);
+
+                    if let Some(catch_parameter) = catch_parameter {
+                        self.bind_target(catch_parameter, Type::Dynamic, true, error_handler)?;
+                    }
+
+                    self.check_node(catch_body, error_handler)?;
+                    self.scopes.pop();
+                }
+
+                if let Some(finally_body) = finally_body {
+                    self.check_node(finally_body, error_handler)?;
+                }
+
+                Type::Dynamic
+            }
+            super::node::Node::MatchStatement { argument, arms, .. } => {
+                self.check_node(argument, error_handler)?;
+                self.check_nodes(arms, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::MatchArm {
+                pattern,
+                guard,
+                body,
+            } => {
+                self.push_scope(false, None);
+                self.bind_target(pattern, Type::Dynamic, true, error_handler)?;
+
+                if let Some(guard) = guard {
+                    self.check_node(guard, error_handler)?;
+                }
+
+                self.check_node(body, error_handler)?;
+                self.scopes.pop();
+
+                Type::Dynamic
+            }
+            super::node::Node::ImportStatement { alias, names, .. } => {
+                for name in alias.iter().chain(names.iter()) {
+                    self.bind_target(name, Type::Dynamic, true, error_handler)?;
+                }
+
+                Type::Dynamic
+            }
+            super::node::Node::LiteralExpression { value } => Self::literal_type(value),
+            super::node::Node::IdentifierExpression { name } => self.lookup(name),
+            super::node::Node::StringExpression { .. } => Type::String,
+            super::node::Node::InterpolatedStringExpression { parts } => {
+                self.check_nodes(parts, error_handler)?;
+
+                Type::String
+            }
+            super::node::Node::InfixExpression {
+                left_argument,
+                right_argument,
+                operator,
+            } => {
+                let left_type: Type = self.check_node(left_argument, error_handler)?;
+                let right_type: Type = self.check_node(right_argument, error_handler)?;
+
+                self.check_infix_expression(&left_type, &right_type, operator, error_handler)?
+            }
+            super::node::Node::PrefixExpression { argument, operator } => {
+                let argument_type: Type = self.check_node(argument, error_handler)?;
+
+                match operator {
+                    super::token::Token::SymbolBang => Type::Bool,
+                    _ if argument_type.is_numeric() || argument_type.is_unchecked() => {
+                        argument_type
+                    }
+                    _ => {
+                        error_handler.set_error(super::error_handler::Error::TypeError(format!(
+                            "line {}: unsupported operand type for {}: {}",
+                            self.line,
+                            operator.type_as_string(),
+                            argument_type.type_as_string()
+                        )));
+
+                        return None;
+                    }
+                }
+            }
+            super::node::Node::PipelineExpression { argument, target } => {
+                self.check_node(argument, error_handler)?;
+                self.check_node(target, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::RangeExpression { start, end, .. } => {
+                for argument in start.iter().chain(end.iter()) {
+                    self.check_node(argument, error_handler)?;
+                }
+
+                Type::Dynamic
+            }
+            super::node::Node::ArrayExpression { elements } => {
+                self.check_nodes(elements, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::TableExpression { properties } => {
+                let mut property_types: Option<Vec<(std::rc::Rc<str>, Type)>> = Some(Vec::new());
+
+                for (key, value, key_type, _) in properties {
+                    if key_type == &super::node::PropertyKeyType::Computed {
+                        self.check_node(key, error_handler)?;
+                    }
+
+                    let value_type: Type = self.check_node(value, error_handler)?;
+
+                    match (key.as_ref(), key_type, property_types.as_mut()) {
+                        (
+                            super::node::Node::IdentifierExpression { name }
+                            | super::node::Node::StringExpression { value: name },
+                            super::node::PropertyKeyType::Static,
+                            Some(property_types),
+                        ) => property_types.push((name.clone(), value_type)),
+                        _ => property_types = None,
+                    }
+                }
+
+                property_types.map_or(Type::Dynamic, Type::Table)
+            }
+            super::node::Node::MemberExpression {
+                target,
+                argument,
+                notation_type,
+            } => {
+                let target_type: Type = self.check_node(target, error_handler)?;
+
+                match (notation_type, argument.as_ref(), target_type) {
+                    (
+                        super::node::MemberNotationType::Dot
+                        | super::node::MemberNotationType::OptionalDot,
+                        super::node::Node::IdentifierExpression { name },
+                        Type::Table(properties),
+                    ) => properties
+                        .into_iter()
+                        .find(|(property_name, _)| property_name == name)
+                        .map_or(Type::Dynamic, |(_, property_type)| property_type),
+                    (
+                        super::node::MemberNotationType::Bracket
+                        | super::node::MemberNotationType::OptionalBracket,
+                        _,
+                        _,
+                    ) => {
+                        self.check_node(argument, error_handler)?;
+
+                        Type::Dynamic
+                    }
+                    _ => Type::Dynamic,
+                }
+            }
+            super::node::Node::FuncExpression {
+                parameters,
+                default_arguments,
+                variadic_parameter,
+                parameter_types,
+                variadic_parameter_type,
+                return_type,
+                body,
+                ..
+            } => self.check_func_expression(
+                parameters,
+                default_arguments,
+                variadic_parameter,
+                parameter_types,
+                variadic_parameter_type,
+                return_type,
+                body,
+                error_handler,
+            )?,
+            super::node::Node::CallExpression {
+                target,
+                arguments,
+                is_optional,
+            } => self.check_call_expression(target, arguments, *is_optional, error_handler)?,
+            super::node::Node::MethodCallExpression {
+                target, arguments, ..
+            } => {
+                self.check_node(target, error_handler)?;
+                self.check_nodes(arguments, error_handler)?;
+
+                Type::Dynamic
+            }
+            super::node::Node::ContinueStatement { .. }
+            | super::node::Node::EmptyStatement
+            | super::node::Node::Attribute { .. }
+            | super::node::Node::LiteralPattern { .. }
+            | super::node::Node::StringPattern { .. }
+            | super::node::Node::IdentifierPattern { .. }
+            | super::node::Node::WildcardPattern
+            | super::node::Node::ArrayPattern { .. }
+            | super::node::Node::RestPattern { .. }
+            | super::node::Node::TablePattern { .. }
+            | super::node::Node::PrimitiveType { .. }
+            | super::node::Node::TableType { .. }
+            | super::node::Node::FuncType { .. }
+            | super::node::Node::UnionType { .. }
+            | super::node::Node::OptionalType { .. } => Type::Dynamic,
+        };
+
+        self.line = line;
+
+        Some(node_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error_handler::{Error, ErrorHandler};
+
+    fn check(input: &str) -> ErrorHandler {
+        let mut error_handler: ErrorHandler = ErrorHandler::new();
+        let mut lexer: super::super::lexer::Lexer<'_> = super::super::lexer::Lexer::new(input);
+        let mut parser: super::super::parser::Parser<'_> =
+            super::super::parser::Parser::new(&mut lexer);
+
+        let program: super::super::node::Node = parser
+            .parse(&mut error_handler)
+            .unwrap_or_else(|| panic!("{input}: {:?}", error_handler.error()));
+
+        super::TypeChecker::new().check(&program, &mut error_handler);
+
+        error_handler
+    }
+
+    fn assert_type_error(input: &str, message: &str) {
+        assert_eq!(
+            check(input).error(),
+            Some(&Error::TypeError(message.to_string())),
+            "{input}"
+        );
+    }
+
+    fn assert_checks(input: &str) {
+        assert_eq!(check(input).error(), None, "{input}");
+    }
+
+    #[test]
+    fn operator_mismatches() {
+        assert_type_error(
+            "x := true + 1",
+            "line 1: unsupported operand types for SymbolPlus: bool and int",
+        );
+        assert_type_error(
+            "x := -\"a\"",
+            "line 1: unsupported operand type for SymbolMinus: string",
+        );
+        assert_checks("x: int? := none\ny := x + 1");
+    }
+
+    #[test]
+    fn errors_report_innermost_statement_line() {
+        assert_type_error(
+            "x := 1\ny := 2\n\nz := x + \"a\"",
+            "line 4: unsupported operand types for SymbolPlus: int and string",
+        );
+        assert_type_error(
+            "f := func {\n    if true {\n        return 1 + \"a\"\n    }\n}",
+            "line 3: unsupported operand types for SymbolPlus: int and string",
+        );
+    }
+
+    #[test]
+    fn annotation_mismatches() {
+        assert_type_error(
+            "n: int := \"a\"",
+            "line 1: type mismatch: cannot assign string to n: int",
+        );
+        assert_type_error(
+            "f := func (a: int) -> string { a }",
+            "line 1: type mismatch: expected return type string, but got int",
+        );
+        assert_type_error(
+            "f := func -> int { return \"a\" }",
+            "line 1: type mismatch: expected return type int, but got string",
+        );
+        assert_type_error(
+            "f := func (a: int = \"s\") { }",
+            "line 1: type mismatch: expected int, but got string for default argument 1",
+        );
+    }
+
+    #[test]
+    fn call_mismatches() {
+        assert_type_error(
+            "f := func (a: int) -> int { a }\nf(\"s\")",
+            "line 2: type mismatch: expected int, but got string for argument 1 of f",
+        );
+        assert_type_error(
+            "f := func (a: int) -> int { a }\nf(1, 2)",
+            "line 2: expected 1 arguments, but got 2 in call to f",
+        );
+        assert_type_error("x := 5\nx()", "line 2: cannot call value of type int");
+    }
+
+    #[test]
+    fn reassigned_locals_are_dynamic() {
+        assert_checks("x := 1\nx = \"a\"\ny := x + 1");
+    }
+
+    #[test]
+    fn table_writes() {
+        assert_type_error(
+            "p: table [name: string] := table [name: \"a\"]\np.name = 1",
+            "line 2: type mismatch: cannot assign int to field name: string",
+        );
+        assert_checks("p := table [name: \"a\"]\np.name = 1\nx := p.name + 1");
+        assert_checks("p := table [n: 1]\nf := func { p.n = \"a\" }\nx := p.n + 1");
+    }
+
+    #[test]
+    fn captured_bindings() {
+        assert_type_error(
+            "x := 1\nf := func { x + \"a\" }",
+            "line 2: unsupported operand types for SymbolPlus: int and string",
+        );
+        assert_checks("x := 1\nf := func { x + \"a\" }\nx = \"s\"");
+        assert_checks("x := 1\nf := func { x + \"a\" }\nx := \"s\"");
+    }
+}